@@ -0,0 +1,333 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs,
+    io::{self, ErrorKind},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Answers known to be wrong for a part, along with the tightest bounds learned from
+/// "too low" and "too high" responses.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rejections {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub too_low: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub too_high: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved { answer: String },
+    KnownWrong,
+    NotAboveLowBound { too_low: String },
+    NotBelowHighBound { too_high: String },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => write!(f, "already solved with {answer}"),
+            Refusal::KnownWrong => write!(f, "answer was already rejected"),
+            Refusal::NotAboveLowBound { too_low } => {
+                write!(f, "answer is not above {too_low}, which is too low")
+            }
+            Refusal::NotBelowHighBound { too_high } => {
+                write!(f, "answer is not below {too_high}, which is too high")
+            }
+        }
+    }
+}
+
+impl Rejections {
+    pub fn check(&self, answer: &str) -> Result<(), Refusal> {
+        if self.wrong.iter().any(|wrong| wrong == answer) {
+            return Err(Refusal::KnownWrong);
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        let bound = |bound: &Option<String>| bound.as_ref().and_then(|b| b.parse::<i128>().ok());
+        if bound(&self.too_low).is_some_and(|low| value <= low) {
+            return Err(Refusal::NotAboveLowBound {
+                too_low: self.too_low.clone().unwrap(),
+            });
+        }
+        if bound(&self.too_high).is_some_and(|high| value >= high) {
+            return Err(Refusal::NotBelowHighBound {
+                too_high: self.too_high.clone().unwrap(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn reject(&mut self, answer: &str, too_low: bool, too_high: bool) {
+        if !self.wrong.iter().any(|wrong| wrong == answer) {
+            self.wrong.push(answer.to_owned());
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return;
+        };
+        let tighter = |bound: &Option<String>, better: fn(i128, i128) -> bool| {
+            bound
+                .as_ref()
+                .and_then(|b| b.parse::<i128>().ok())
+                .is_none_or(|b| better(value, b))
+        };
+        if too_low && tighter(&self.too_low, |v, b| v > b) {
+            self.too_low = Some(answer.to_owned());
+        }
+        if too_high && tighter(&self.too_high, |v, b| v < b) {
+            self.too_high = Some(answer.to_owned());
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VariantAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rejected: BTreeMap<String, Rejections>,
+}
+
+impl VariantAnswers {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part1 = Some(answer),
+            2 => self.part2 = Some(answer),
+            _ => panic!("no answer slot for part {part}"),
+        }
+    }
+
+    pub fn rejections(&self, part: u8) -> Option<&Rejections> {
+        self.rejected.get(&format!("part{part}"))
+    }
+
+    pub fn rejections_mut(&mut self, part: u8) -> &mut Rejections {
+        self.rejected.entry(format!("part{part}")).or_default()
+    }
+
+    /// Checks whether submitting `answer` could still be useful given what is already known.
+    pub fn check_submission(&self, part: u8, answer: &str) -> Result<(), Refusal> {
+        if let Some(known) = self.get(part) {
+            return Err(Refusal::AlreadySolved {
+                answer: known.to_owned(),
+            });
+        }
+        self.rejections(part)
+            .map_or(Ok(()), |rejections| rejections.check(answer))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+/// Known answers, stored as `[<year>.dayNN.<variant>]` tables holding `part1` and `part2`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnswerStore {
+    years: BTreeMap<String, BTreeMap<String, BTreeMap<String, VariantAnswers>>>,
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+impl AnswerStore {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content).map_err(|e| {
+                io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid answer file {}: {e}", path.display()),
+                )
+            }),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let content =
+            toml::to_string(self).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+        fs::write(path, content)
+    }
+
+    pub fn variant(&self, year: u32, day: u8, variant: &str) -> Option<&VariantAnswers> {
+        self.years
+            .get(&year.to_string())?
+            .get(&day_key(day))?
+            .get(variant)
+    }
+
+    pub fn variants(&self, year: u32, day: u8) -> impl Iterator<Item = &str> {
+        self.years
+            .get(&year.to_string())
+            .and_then(|days| days.get(&day_key(day)))
+            .into_iter()
+            .flat_map(|variants| variants.keys().map(String::as_str))
+    }
+
+    pub fn get(&self, year: u32, day: u8, variant: &str, part: u8) -> Option<&str> {
+        self.variant(year, day, variant)?.get(part)
+    }
+
+    pub fn variant_mut(&mut self, year: u32, day: u8, variant: &str) -> &mut VariantAnswers {
+        self.years
+            .entry(year.to_string())
+            .or_default()
+            .entry(day_key(day))
+            .or_default()
+            .entry(variant.to_owned())
+            .or_default()
+    }
+
+    pub fn set(&mut self, year: u32, day: u8, variant: &str, part: u8, answer: String) {
+        self.variant_mut(year, day, variant).set(part, answer);
+    }
+
+    pub fn check(&self, year: u32, day: u8, variant: &str, part: u8, answer: &str) -> Verdict {
+        match self.get(year, day, variant, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_owned(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::{AnswerStore, Refusal, Rejections, Verdict};
+
+    #[test]
+    fn test_answer_store() {
+        let store = AnswerStore::parse(
+            r#"
+[2024.day01.real]
+part1 = "11"
+part2 = "31"
+
+[2024.day01.stress]
+part1 = "42"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            store.variants(2024, 1).collect_vec(),
+            vec!["real", "stress"]
+        );
+        assert_eq!(store.variants(2024, 2).count(), 0);
+        assert_eq!(store.check(2024, 1, "real", 1, "11"), Verdict::Pass);
+        assert_eq!(
+            store.check(2024, 1, "real", 2, "30"),
+            Verdict::Fail {
+                expected: "31".to_owned()
+            }
+        );
+        assert_eq!(store.check(2024, 1, "stress", 2, "1"), Verdict::Missing);
+        assert_eq!(store.check(2023, 1, "real", 1, "11"), Verdict::Missing);
+    }
+
+    #[test]
+    fn test_answer_store_roundtrip() {
+        let mut store = AnswerStore::default();
+        store.set(2024, 3, "real", 2, "48".to_owned());
+        store.set(2024, 3, "real", 1, "161".to_owned());
+
+        let content = toml::to_string(&store).unwrap();
+        assert_eq!(
+            content,
+            "[2024.day03.real]\npart1 = \"161\"\npart2 = \"48\"\n"
+        );
+        assert_eq!(AnswerStore::parse(&content).unwrap(), store);
+    }
+
+    #[test]
+    fn test_rejections() {
+        let mut rejections = Rejections::default();
+        rejections.reject("100", true, false);
+        rejections.reject("50", true, false);
+        rejections.reject("900", false, true);
+        rejections.reject("abc", false, false);
+
+        assert_eq!(rejections.too_low.as_deref(), Some("100"));
+        assert_eq!(rejections.too_high.as_deref(), Some("900"));
+        assert_eq!(rejections.check("abc"), Err(Refusal::KnownWrong));
+        assert_eq!(rejections.check("50"), Err(Refusal::KnownWrong));
+        assert_eq!(
+            rejections.check("75"),
+            Err(Refusal::NotAboveLowBound {
+                too_low: "100".to_owned()
+            })
+        );
+        assert_eq!(
+            rejections.check("1000"),
+            Err(Refusal::NotBelowHighBound {
+                too_high: "900".to_owned()
+            })
+        );
+        assert_eq!(rejections.check("500"), Ok(()));
+        assert_eq!(rejections.check("xyz"), Ok(()));
+    }
+
+    #[test]
+    fn test_rejections_roundtrip() {
+        let mut store = AnswerStore::default();
+        store.set(2024, 1, "real", 1, "11".to_owned());
+        store
+            .variant_mut(2024, 1, "real")
+            .rejections_mut(2)
+            .reject("30", true, false);
+
+        let content = toml::to_string(&store).unwrap();
+        assert_eq!(
+            content,
+            "[2024.day01.real]\npart1 = \"11\"\n\n[2024.day01.real.rejected.part2]\nwrong = [\"30\"]\ntoo_low = \"30\"\n"
+        );
+        let store = AnswerStore::parse(&content).unwrap();
+        let answers = store.variant(2024, 1, "real").unwrap();
+        assert_eq!(
+            answers.check_submission(1, "12"),
+            Err(Refusal::AlreadySolved {
+                answer: "11".to_owned()
+            })
+        );
+        assert_eq!(
+            answers.check_submission(2, "29"),
+            Err(Refusal::NotAboveLowBound {
+                too_low: "30".to_owned()
+            })
+        );
+        assert_eq!(answers.check_submission(2, "31"), Ok(()));
+    }
+}
@@ -0,0 +1,853 @@
+use std::{
+    collections::BTreeSet,
+    io::{self, IsTerminal, Read},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use advent_of_code_2024::{
+    answers::{AnswerStore, Verdict},
+    debug_output::DebugOutput,
+    inputs::{read_input, InputStore, DEFAULT_VARIANT},
+    memory::CountingAllocator,
+    runner::{find_year, latest_year, DaySolver, Strictness, Year},
+    scaffold::scaffold_day,
+    submit::{submit, Client, Outcome, DEFAULT_ENDPOINT},
+    summary::{render as render_summary, run_all, silence_day_panics},
+    timing::{bench_day, render, ReportFormat},
+    trace::{self, PartTrace, TraceFormat},
+};
+use clap::{Args, Parser, Subcommand};
+
+/// Only counts inside `bench --memory` measurements.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run the Advent of Code solvers")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Directory holding the inputs as `<year>/dayNN/<variant>.txt`
+    #[arg(long, global = true, default_value = "inputs")]
+    inputs_dir: PathBuf,
+    /// Known-answer file
+    #[arg(long, global = true, default_value = "answers.toml")]
+    answers: PathBuf,
+    /// Event year, the latest registered one if omitted
+    #[arg(long, global = true)]
+    year: Option<u32>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solvers of a day, or of every registered day
+    Run(RunArgs),
+    /// Run every registered day in parallel and show a day × part summary
+    All(AllArgs),
+    /// Check every registered solver against the known answers of each input variant
+    Verify(VerifyArgs),
+    /// Time the input generator and each solver separately
+    Bench(BenchArgs),
+    /// Run the main solver and every alternative solver of each part, flagging disagreements
+    Diff(DiffArgs),
+    /// Generate a random input from a seed, for benchmarking or fuzzing
+    Generate(GenerateArgs),
+    /// Compare every solver of a day on many random inputs, reporting the seeds they disagree on
+    Stress(StressArgs),
+    /// Create a new day module from `src/template.rs` and register it
+    New(NewArgs),
+    /// Submit an answer and record the response in the known-answer file
+    Submit(SubmitArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run, all days if omitted
+    #[arg(long)]
+    day: Option<u8>,
+    /// Part to run, all parts of the day if omitted
+    #[arg(long, requires = "day")]
+    part: Option<u8>,
+    /// Input file, `-` for stdin. Piped stdin is used when neither this nor a variant is given
+    #[arg(long, requires = "day", conflicts_with_all = ["examples", "variant"])]
+    input: Option<PathBuf>,
+    /// Input variant to read from the inputs directory
+    #[arg(long, conflicts_with = "examples")]
+    variant: Option<String>,
+    /// Run the registered examples instead of the input, checking their answers
+    #[arg(long)]
+    examples: bool,
+    /// Reject trailing whitespace or blank lines at the end of the input
+    #[arg(long)]
+    strict: bool,
+    /// Print the events traced by the solvers after each answer
+    #[arg(long, value_enum, conflicts_with = "examples")]
+    trace: Option<TraceFormat>,
+    /// Write the maps, images and graphs of the debug hooks under this directory, one
+    /// subdirectory per part
+    #[arg(long, env = "AOC_DEBUG_DIR", conflicts_with = "examples")]
+    debug_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct AllArgs {
+    /// Input variant to read from the inputs directory
+    #[arg(long, default_value = DEFAULT_VARIANT)]
+    variant: String,
+    /// Seconds after which a generator or solver is reported as timed out
+    #[arg(long, default_value = "10", value_parser = parse_seconds)]
+    timeout: Duration,
+}
+
+fn parse_seconds(seconds: &str) -> Result<Duration, String> {
+    let seconds = seconds.parse::<f64>().map_err(|e| e.to_string())?;
+    Duration::try_from_secs_f64(seconds).map_err(|e| e.to_string())
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Day to verify, all days if omitted
+    #[arg(long)]
+    day: Option<u8>,
+    /// Store the answers of the parts that have no known answer yet
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Args)]
+struct DiffArgs {
+    /// Day to check, all days with alternative solvers if omitted
+    #[arg(long)]
+    day: Option<u8>,
+    /// Part to check, all parts of the day if omitted
+    #[arg(long, requires = "day")]
+    part: Option<u8>,
+    /// Input variant to read from the inputs directory
+    #[arg(long, default_value = DEFAULT_VARIANT, conflicts_with = "examples")]
+    variant: String,
+    /// Run on the registered examples instead of the input
+    #[arg(long)]
+    examples: bool,
+    /// Alternative solver to compare with the main one, can be repeated; all of them if omitted
+    #[arg(long = "solver")]
+    solvers: Vec<String>,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Day to generate an input for
+    #[arg(long)]
+    day: u8,
+    /// Seed of the generator, the same seed and size always give the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Size of the input, in the unit of the day (lines, reports, grid side...)
+    #[arg(long, default_value_t = 1000)]
+    size: usize,
+    /// Write the input as this variant of the inputs directory instead of to stdout
+    #[arg(long)]
+    variant: Option<String>,
+}
+
+#[derive(Args)]
+struct StressArgs {
+    /// Day to check, all days with a generator if omitted
+    #[arg(long)]
+    day: Option<u8>,
+    /// First seed, the following runs use the next seeds
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Number of random inputs to check
+    #[arg(long, default_value_t = 100)]
+    runs: u64,
+    /// Size of each input
+    #[arg(long, default_value_t = 20)]
+    size: usize,
+    /// Alternative solver to compare with the main one, can be repeated; all of them if omitted
+    #[arg(long = "solver")]
+    solvers: Vec<String>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to time, all days if omitted
+    #[arg(long)]
+    day: Option<u8>,
+    /// Part to time, all parts of the day if omitted
+    #[arg(long, requires = "day")]
+    part: Option<u8>,
+    /// Input variant to read from the inputs directory
+    #[arg(long, default_value = DEFAULT_VARIANT)]
+    variant: String,
+    /// Number of times each step is run
+    #[arg(long, default_value_t = 10)]
+    repeat: usize,
+    /// Output format of the report
+    #[arg(long, value_enum, default_value_t = ReportFormat::Table)]
+    format: ReportFormat,
+    /// Also report the peak heap memory and the allocations of each step
+    #[arg(long)]
+    memory: bool,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Root of the crate the day is added to
+    #[arg(long, default_value = ".")]
+    root: PathBuf,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day of the answer
+    #[arg(long)]
+    day: u8,
+    /// Part of the answer
+    #[arg(long)]
+    part: u8,
+    /// Answer to submit, computed from the variant input if omitted
+    #[arg(long)]
+    answer: Option<String>,
+    /// Input variant the answer belongs to
+    #[arg(long, default_value = DEFAULT_VARIANT)]
+    variant: String,
+    /// Base URL of the puzzle site
+    #[arg(long, env = "AOC_ENDPOINT", default_value = DEFAULT_ENDPOINT)]
+    endpoint: String,
+    /// Session cookie of the account owning the variant input
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: String,
+}
+
+fn read_stdin() -> io::Result<String> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    Ok(input)
+}
+
+fn piped_stdin(args: &RunArgs) -> io::Result<Option<String>> {
+    let piped = args.day.is_some()
+        && args.input.is_none()
+        && args.variant.is_none()
+        && !args.examples
+        && !io::stdin().is_terminal();
+    if !piped {
+        return Ok(None);
+    }
+
+    let input = read_stdin()?;
+    Ok(Some(input).filter(|input| !input.is_empty()))
+}
+
+fn load_input(args: &RunArgs, store: &InputStore, day: u8) -> io::Result<String> {
+    match &args.input {
+        Some(path) if path.as_os_str() == "-" => read_stdin(),
+        Some(path) => read_input(path),
+        None => store.read(day, args.variant.as_deref().unwrap_or(DEFAULT_VARIANT)),
+    }
+}
+
+fn run_day(
+    day: &DaySolver,
+    year: u32,
+    input: &str,
+    args: &RunArgs,
+    debug_output: Option<&DebugOutput>,
+) -> Result<(), String> {
+    let strictness = match args.strict {
+        true => Strictness::Strict,
+        false => Strictness::Lenient,
+    };
+    let parts = match args.part {
+        Some(part) => vec![day
+            .part(part)
+            .ok_or_else(|| format!("Day {}: part {part} is not implemented", day.day))?],
+        None => day.parts.iter().collect(),
+    };
+
+    let generated = day
+        .generate(input, strictness)
+        .map_err(|e| format!("Day {}: failed to parse input\n{e}", day.day))?;
+    for part in parts {
+        let solve = || match args.trace {
+            Some(_) => trace::record(|| part.solve(generated.as_ref())),
+            None => (part.solve(generated.as_ref()), vec![]),
+        };
+        let ((answer, events), debug_dir) = match debug_output {
+            Some(output) => output.capture((year, day.day, part.part), solve),
+            None => (solve(), None),
+        };
+        let answer = answer.to_string();
+
+        match args.trace {
+            Some(format) => {
+                let trace = PartTrace {
+                    day: day.day,
+                    part: part.part,
+                    answer,
+                    events,
+                };
+                print!("{}", trace.render(format));
+            }
+            None => {
+                let separator = if answer.contains('\n') { "\n" } else { " " };
+                println!("Day {} - Part {}:{separator}{answer}", day.day, part.part);
+            }
+        }
+        if let Some(dir) = debug_dir {
+            eprintln!(
+                "Day {} - Part {}: debug output in {}",
+                day.day,
+                part.part,
+                dir.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn run_examples(day: &DaySolver, part: Option<u8>) -> Result<(), String> {
+    let mut failed = 0;
+    for example in day.examples {
+        let checks = day.check_example(example, part).map_err(|e| {
+            format!(
+                "Day {} - {}: failed to parse example\n{e}",
+                day.day, example.name
+            )
+        })?;
+        for check in checks {
+            let status = if check.is_ok() {
+                "ok".to_owned()
+            } else {
+                failed += 1;
+                format!("FAILED, expected {}", check.expected)
+            };
+            println!(
+                "Day {} - Part {} - {}: {} ({status})",
+                day.day, check.part, example.name, check.answer
+            );
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("Day {}: {failed} example(s) failed", day.day)),
+    }
+}
+
+fn select_year(year: Option<u32>) -> Result<&'static Year, String> {
+    match year {
+        Some(year) => find_year(year).ok_or_else(|| format!("Year {year} is not implemented")),
+        None => Ok(latest_year()),
+    }
+}
+
+fn select_days(year: Option<u32>, day: Option<u8>) -> Result<Vec<&'static DaySolver>, String> {
+    let year = select_year(year)?;
+    match day {
+        Some(day) => year
+            .find_day(day)
+            .map(|day| vec![day])
+            .ok_or_else(|| format!("Day {day} of {} is not implemented", year.year)),
+        None => Ok(year.days.iter().collect()),
+    }
+}
+
+fn run(args: RunArgs, year: Option<u32>, store: &InputStore) -> ExitCode {
+    let days = match select_days(year, args.day) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let stdin = match piped_stdin(&args) {
+        Ok(stdin) => stdin,
+        Err(e) => {
+            eprintln!("Could not read stdin: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let debug_output = args.debug_dir.as_ref().map(DebugOutput::new);
+    let mut status = ExitCode::SUCCESS;
+    for day in days {
+        let result = if args.examples {
+            run_examples(day, args.part)
+        } else {
+            match &stdin {
+                Some(input) => Ok(input.clone()),
+                None => load_input(&args, store, day.day),
+            }
+            .map_err(|e| format!("Day {}: {e}", day.day))
+            .and_then(|input| run_day(day, store.year(), &input, &args, debug_output.as_ref()))
+        };
+        if let Err(e) = result {
+            eprintln!("{e}");
+            status = ExitCode::FAILURE;
+        }
+    }
+    status
+}
+
+fn all(args: AllArgs, year: Option<u32>, store: &InputStore) -> ExitCode {
+    let days = match select_days(year, None) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let jobs = days
+        .into_iter()
+        .map(|day| (day, store.read(day.day, &args.variant)))
+        .collect();
+
+    silence_day_panics();
+    let reports = run_all(jobs, args.timeout);
+
+    print!("{}", render_summary(&reports));
+    match reports
+        .iter()
+        .all(|r| r.cells.iter().all(|c| c.status.is_ok()))
+    {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
+
+#[derive(Default)]
+struct VerifyCounts {
+    pass: usize,
+    fail: usize,
+    missing: usize,
+}
+
+fn verify_variant(
+    day: &DaySolver,
+    variant: &str,
+    store: &InputStore,
+    answers: &mut AnswerStore,
+    record: bool,
+    counts: &mut VerifyCounts,
+) {
+    let year = store.year();
+    let has_answers = answers.variant(year, day.day, variant).is_some();
+    let label = |part: u8| format!("Day {} - Part {part} - {variant}", day.day);
+
+    let input = store
+        .read(day.day, variant)
+        .map_err(|e| e.to_string())
+        .and_then(|input| {
+            day.generate(&input, Strictness::Lenient)
+                .map_err(|e| format!("failed to parse input\n{e}"))
+        });
+    let input = match input {
+        Ok(input) => input,
+        Err(e) if has_answers => {
+            for part in day.parts {
+                if answers.get(year, day.day, variant, part.part).is_some() {
+                    println!("{}: FAIL ({e})", label(part.part));
+                    counts.fail += 1;
+                }
+            }
+            return;
+        }
+        Err(_) => {
+            println!("Day {} - {variant}: missing input", day.day);
+            counts.missing += day.parts.len();
+            return;
+        }
+    };
+
+    for part in day.parts {
+        let answer = part.solve(input.as_ref()).to_string();
+        match answers.check(year, day.day, variant, part.part, &answer) {
+            Verdict::Pass => {
+                println!("{}: pass", label(part.part));
+                counts.pass += 1;
+            }
+            Verdict::Fail { expected } => {
+                println!(
+                    "{}: FAIL (got {answer}, expected {expected})",
+                    label(part.part)
+                );
+                counts.fail += 1;
+            }
+            Verdict::Missing if record => {
+                println!("{}: recorded {answer}", label(part.part));
+                answers.set(year, day.day, variant, part.part, answer);
+            }
+            Verdict::Missing => {
+                println!("{}: missing (got {answer})", label(part.part));
+                counts.missing += 1;
+            }
+        }
+    }
+}
+
+fn verify(
+    args: VerifyArgs,
+    year: Option<u32>,
+    store: &InputStore,
+    answers_path: &Path,
+) -> ExitCode {
+    let result = select_days(year, args.day).and_then(|days| {
+        let mut answers = AnswerStore::load(answers_path).map_err(|e| e.to_string())?;
+        let mut counts = VerifyCounts::default();
+
+        for day in days {
+            let variants = answers
+                .variants(store.year(), day.day)
+                .map(str::to_owned)
+                .chain([DEFAULT_VARIANT.to_owned()])
+                .collect::<BTreeSet<_>>();
+            for variant in variants {
+                verify_variant(day, &variant, store, &mut answers, args.record, &mut counts);
+            }
+        }
+
+        if args.record {
+            answers
+                .save(answers_path)
+                .map_err(|e| format!("could not write {}: {e}", answers_path.display()))?;
+        }
+
+        println!(
+            "{} passed, {} failed, {} missing",
+            counts.pass, counts.fail, counts.missing
+        );
+        Ok(counts.fail)
+    });
+
+    match result {
+        Ok(0) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The solvers of `solvers` the day does not have.
+fn missing_solvers<'a>(day: &DaySolver, solvers: &'a [String]) -> Vec<&'a str> {
+    let variants = day.variants();
+    solvers
+        .iter()
+        .filter(|s| !variants.iter().any(|v| v.name == *s))
+        .map(String::as_str)
+        .collect()
+}
+
+fn diff_input(
+    day: &DaySolver,
+    part: Option<u8>,
+    solvers: &[String],
+    label: &str,
+    input: &str,
+) -> Result<bool, String> {
+    let checks = day
+        .check_variants(input, part, solvers, Strictness::Lenient)
+        .map_err(|e| format!("Day {} - {label}: failed to parse input\n{e}", day.day))?;
+
+    let mut agree = true;
+    for check in checks {
+        for (name, answer) in &check.answers {
+            println!(
+                "Day {} - Part {} - {label} - {name}: {answer}",
+                day.day, check.part
+            );
+        }
+        if !check.agrees() {
+            println!("Day {} - Part {} - {label}: DISAGREE", day.day, check.part);
+            agree = false;
+        }
+    }
+    Ok(agree)
+}
+
+fn diff(args: DiffArgs, year: Option<u32>, store: &InputStore) -> ExitCode {
+    let days = match select_days(year, args.day) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let mut checked = false;
+    for day in days {
+        if args.day.is_none() && day.variants().is_empty() {
+            continue;
+        }
+        let missing = missing_solvers(day, &args.solvers);
+        if !missing.is_empty() {
+            if args.day.is_some() {
+                eprintln!("Day {}: no solver named {}", day.day, missing.join(", "));
+                status = ExitCode::FAILURE;
+            }
+            continue;
+        }
+        checked = true;
+
+        let inputs = match args.examples {
+            true => day
+                .examples
+                .iter()
+                .map(|e| Ok((e.name.to_owned(), e.input.to_owned())))
+                .collect(),
+            false => vec![store
+                .read(day.day, &args.variant)
+                .map(|input| (args.variant.clone(), input))
+                .map_err(|e| format!("Day {}: {e}", day.day))],
+        };
+        for input in inputs {
+            let result = input.and_then(|(label, input)| {
+                diff_input(day, args.part, &args.solvers, &label, &input)
+            });
+            match result {
+                Ok(true) => {}
+                Ok(false) => status = ExitCode::FAILURE,
+                Err(e) => {
+                    eprintln!("{e}");
+                    status = ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+
+    if !checked && args.day.is_none() {
+        match args.solvers.is_empty() {
+            true => println!("No day has alternative solvers"),
+            false => println!("No day has the requested solvers"),
+        }
+    }
+    status
+}
+
+fn generate(args: GenerateArgs, year: Option<u32>, store: &InputStore) -> ExitCode {
+    let result = select_days(year, Some(args.day)).and_then(|days| {
+        let input = days[0]
+            .random_input(args.seed, args.size)
+            .ok_or_else(|| "no input generator".to_owned())?;
+        match &args.variant {
+            Some(variant) => {
+                let path = store
+                    .write(args.day, variant, &input)
+                    .map_err(|e| e.to_string())?;
+                println!("Wrote {}", path.display());
+            }
+            None => println!("{input}"),
+        }
+        Ok(())
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Day {}: {e}", args.day);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Checks one random input, returning the description of every failure.
+fn stress_seed(day: &DaySolver, seed: u64, size: usize, solvers: &[String]) -> Vec<String> {
+    let Some(input) = day.random_input(seed, size) else {
+        return vec![];
+    };
+    match day.check_variants(&input, None, solvers, Strictness::Lenient) {
+        Ok(checks) => checks
+            .iter()
+            .filter(|check| !check.agrees())
+            .map(|check| {
+                let answers = check
+                    .answers
+                    .iter()
+                    .map(|(name, answer)| format!("{name}: {answer}"))
+                    .collect::<Vec<_>>();
+                format!("part {} disagrees ({})", check.part, answers.join(", "))
+            })
+            .collect(),
+        Err(e) => vec![format!("failed to parse input\n{e}")],
+    }
+}
+
+fn stress(args: StressArgs, year: Option<u32>) -> ExitCode {
+    let days = match select_days(year, args.day) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    for day in days {
+        if day.random_input(args.seed, args.size).is_none() {
+            if args.day.is_some() {
+                eprintln!("Day {}: no input generator", day.day);
+                status = ExitCode::FAILURE;
+            }
+            continue;
+        }
+        let missing = missing_solvers(day, &args.solvers);
+        if !missing.is_empty() {
+            if args.day.is_some() {
+                eprintln!("Day {}: no solver named {}", day.day, missing.join(", "));
+                status = ExitCode::FAILURE;
+            }
+            continue;
+        }
+
+        let mut failed = 0;
+        for seed in args.seed..args.seed.saturating_add(args.runs) {
+            let failures = stress_seed(day, seed, args.size, &args.solvers);
+            for failure in &failures {
+                println!("Day {} - seed {seed}: {failure}", day.day);
+            }
+            failed += !failures.is_empty() as u64;
+        }
+        println!(
+            "Day {}: {} of {} inputs passed",
+            day.day,
+            args.runs - failed,
+            args.runs
+        );
+        if failed > 0 {
+            status = ExitCode::FAILURE;
+        }
+    }
+    status
+}
+
+fn bench(args: BenchArgs, year: Option<u32>, store: &InputStore) -> ExitCode {
+    let days = match select_days(year, args.day) {
+        Ok(days) => days,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let mut timings = vec![];
+    for day in days {
+        let result = store
+            .read(day.day, &args.variant)
+            .map_err(|e| e.to_string())
+            .and_then(|input| {
+                bench_day(day, &input, args.part, args.repeat, args.memory)
+                    .map_err(|e| format!("failed to parse input\n{e}"))
+            });
+        match result {
+            Ok(day_timings) => timings.extend(day_timings),
+            Err(e) => {
+                eprintln!("Day {}: {e}", day.day);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    print!("{}", render(&timings, args.format));
+    status
+}
+
+fn new_day(args: NewArgs, store: &InputStore) -> ExitCode {
+    match scaffold_day(&args.root, store, store.year(), args.day) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Could not create day {}: {e}", args.day);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn solve(day: &DaySolver, part: u8, input: &str) -> Result<String, String> {
+    let solver = day
+        .part(part)
+        .ok_or_else(|| format!("part {part} is not implemented"))?;
+    let generated = day
+        .generate(input, Strictness::Lenient)
+        .map_err(|e| format!("failed to parse input\n{e}"))?;
+    Ok(solver.solve(generated.as_ref()).to_string())
+}
+
+fn submit_answer(
+    args: SubmitArgs,
+    year: Option<u32>,
+    store: &InputStore,
+    answers_path: &Path,
+) -> ExitCode {
+    let result = select_days(year, Some(args.day)).and_then(|days| {
+        let answer = match args.answer {
+            Some(answer) => answer,
+            None => store
+                .read(args.day, &args.variant)
+                .map_err(|e| e.to_string())
+                .and_then(|input| solve(days[0], args.part, &input))?,
+        };
+
+        let mut answers = AnswerStore::load(answers_path).map_err(|e| e.to_string())?;
+        let client = Client::new(&args.endpoint, &args.session);
+        let outcome = submit(
+            &client,
+            &mut answers,
+            (store.year(), args.day, &args.variant),
+            args.part,
+            &answer,
+        )
+        .map_err(|e| e.to_string())?;
+        answers
+            .save(answers_path)
+            .map_err(|e| format!("could not write {}: {e}", answers_path.display()))?;
+
+        println!(
+            "Day {} - Part {} - {}: {answer} is {outcome}",
+            args.day, args.part, args.variant
+        );
+        Ok(outcome)
+    });
+
+    match result {
+        Ok(Outcome::Correct) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("Day {} - Part {}: {e}", args.day, args.part);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let year = cli.year;
+    let store = InputStore::new(&cli.inputs_dir, year.unwrap_or(latest_year().year));
+
+    match cli.command {
+        Command::Run(args) => run(args, year, &store),
+        Command::All(args) => all(args, year, &store),
+        Command::Verify(args) => verify(args, year, &store, &cli.answers),
+        Command::Bench(args) => bench(args, year, &store),
+        Command::Diff(args) => diff(args, year, &store),
+        Command::Generate(args) => generate(args, year, &store),
+        Command::Stress(args) => stress(args, year),
+        Command::New(args) => new_day(args, &store),
+        Command::Submit(args) => submit_answer(args, year, &store, &cli.answers),
+    }
+}
@@ -0,0 +1,164 @@
+use std::{
+    cell::RefCell,
+    fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Where the debug hooks of a run (printed maps, images, graphs) write their artifacts. Each
+/// part gets its own directory, named by year, day, part and the time the run started.
+pub struct DebugOutput {
+    root: PathBuf,
+    started: SystemTime,
+}
+
+struct Artifacts {
+    dir: PathBuf,
+    written: usize,
+}
+
+thread_local! {
+    static ARTIFACTS: RefCell<Option<Artifacts>> = const { RefCell::new(None) };
+}
+
+impl DebugOutput {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            started: SystemTime::now(),
+        }
+    }
+
+    pub fn part_dir(&self, year: u32, day: u8, part: u8) -> PathBuf {
+        self.root.join(format!(
+            "{year}-day{day:02}-part{part}-{}",
+            utc_timestamp(self.started)
+        ))
+    }
+
+    /// Runs `f` with the debug hooks of the current thread enabled, returning the directory
+    /// holding their artifacts if any was written.
+    pub fn capture<T>(
+        &self,
+        (year, day, part): (u32, u8, u8),
+        f: impl FnOnce() -> T,
+    ) -> (T, Option<PathBuf>) {
+        let outer = ARTIFACTS.replace(Some(Artifacts {
+            dir: self.part_dir(year, day, part),
+            written: 0,
+        }));
+        let value = f();
+        let artifacts = ARTIFACTS
+            .replace(outer)
+            .expect("artifacts should still be set");
+        (value, (artifacts.written > 0).then_some(artifacts.dir))
+    }
+}
+
+/// Whether the debug hooks run on the current thread.
+pub fn is_enabled() -> bool {
+    ARTIFACTS.with_borrow(Option::is_some)
+}
+
+/// Path of the next artifact of the current part, numbered in the order they are written so
+/// the names are the same from one run to the next. `None` when debug output is off.
+pub fn artifact(name: &str) -> Option<PathBuf> {
+    ARTIFACTS.with_borrow_mut(|artifacts| {
+        let artifacts = artifacts.as_mut()?;
+        fs::create_dir_all(&artifacts.dir).expect("debug output directory should be created");
+        artifacts.written += 1;
+        Some(
+            artifacts
+                .dir
+                .join(format!("{:03}-{name}", artifacts.written)),
+        )
+    })
+}
+
+/// Formats the time as `YYYYMMDDTHHMMSS.mmmZ`.
+fn utc_timestamp(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let seconds = since_epoch.as_secs();
+    let (days, seconds) = (seconds / 86400, seconds % 86400);
+
+    // Civil date of a day count, from Howard Hinnant's `civil_from_days`.
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+
+    format!(
+        "{year:04}{month:02}{day:02}T{:02}{:02}{:02}.{:03}Z",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+/// Writes a text artifact, doing nothing when debug output is off.
+pub(crate) fn write_text(name: &str, contents: &str) -> Option<PathBuf> {
+    let path = artifact(name)?;
+    fs::write(&path, contents)
+        .unwrap_or_else(|e| panic!("could not write {}: {e}", path.display()));
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        time::{Duration, UNIX_EPOCH},
+    };
+
+    use ndarray::array;
+
+    use super::{artifact, is_enabled, utc_timestamp, DebugOutput};
+    use crate::utils::grid_utils::print_map;
+
+    #[test]
+    fn test_capture() {
+        let root = env::temp_dir().join(format!("aoc-debug-output-{}", std::process::id()));
+        let output = DebugOutput::new(&root);
+
+        assert!(artifact("map.txt").is_none());
+        let ((), dir) = output.capture((2024, 4, 1), || assert!(is_enabled()));
+        assert_eq!(dir, None);
+        assert!(!root.exists());
+
+        let ((), dir) = output.capture((2024, 4, 2), || {
+            print_map(&array![[true, false], [false, true]]);
+            print_map(&array![[true]]);
+        });
+        let dir = dir.unwrap();
+        assert!(!is_enabled());
+        assert_eq!(dir, output.part_dir(2024, 4, 2));
+        assert!(dir
+            .file_name()
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with("2024-day04-part2-"));
+        assert_eq!(
+            fs::read_to_string(dir.join("001-map.txt")).unwrap(),
+            "##  \n  ##\n"
+        );
+        assert_eq!(fs::read_to_string(dir.join("002-map.txt")).unwrap(), "##\n");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_utc_timestamp() {
+        assert_eq!(utc_timestamp(UNIX_EPOCH), "19700101T000000.000Z");
+        assert_eq!(
+            utc_timestamp(UNIX_EPOCH + Duration::from_millis(1_733_011_200_250 + 3_723_000)),
+            "20241201T010203.250Z"
+        );
+    }
+}
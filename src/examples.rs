@@ -0,0 +1,61 @@
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    pub fn expected(&self, part: u8) -> Option<&'static str> {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => None,
+        }
+    }
+}
+
+/// Declares the puzzle examples of a day as `EXAMPLES`, generating a test for each of them that
+/// runs the normalized input through `input_generator` and checks the answers given for
+/// `solve_part1` and `solve_part2`.
+///
+/// ```ignore
+/// examples! {
+///     example1 => "3   4\n4   3", part1: "0", part2: "7";
+/// }
+/// ```
+macro_rules! examples {
+    (@expected) => { None };
+    (@expected $answer:literal) => { Some($answer) };
+    ($($name:ident => $input:expr $(, part1: $part1:literal)? $(, part2: $part2:literal)?);* $(;)?) => {
+        pub const EXAMPLES: &[$crate::examples::Example] = &[$(
+            $crate::examples::Example {
+                name: stringify!($name),
+                input: $input,
+                part1: examples!(@expected $($part1)?),
+                part2: examples!(@expected $($part2)?),
+            }
+        ),*];
+
+        #[cfg(test)]
+        mod example_tests {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $(
+                #[test]
+                fn $name() {
+                    let i = $crate::utils::normalize::normalize_input(
+                        $input,
+                        $crate::utils::normalize::Strictness::Strict,
+                    )
+                    .and_then(|input| input_generator(&input))
+                    .unwrap();
+
+                    $(assert_eq!(solve_part1(&i).to_string(), $part1, "part 1");)?
+                    $(assert_eq!(solve_part2(&i).to_string(), $part2, "part 2");)?
+                }
+            )*
+        }
+    };
+}
@@ -0,0 +1,99 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_VARIANT: &str = "real";
+
+/// Puzzle inputs kept on disk as `<root>/<year>/dayNN/<variant>.txt`.
+pub struct InputStore {
+    root: PathBuf,
+    year: u32,
+}
+
+impl InputStore {
+    pub fn new(root: impl Into<PathBuf>, year: u32) -> Self {
+        Self {
+            root: root.into(),
+            year,
+        }
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    pub fn day_dir(&self, day: u8) -> PathBuf {
+        self.root
+            .join(self.year.to_string())
+            .join(format!("day{day:02}"))
+    }
+
+    pub fn path(&self, day: u8, variant: &str) -> PathBuf {
+        self.day_dir(day).join(format!("{variant}.txt"))
+    }
+
+    pub fn read(&self, day: u8, variant: &str) -> io::Result<String> {
+        read_input(&self.path(day, variant))
+    }
+
+    /// Writes the input of a variant, creating the day directory if needed.
+    pub fn write(&self, day: u8, variant: &str, input: &str) -> io::Result<PathBuf> {
+        let path = self.path(day, variant);
+        fs::create_dir_all(self.day_dir(day))?;
+        fs::write(&path, input).map_err(|e| {
+            io::Error::new(e.kind(), format!("could not write {}: {e}", path.display()))
+        })?;
+        Ok(path)
+    }
+
+    pub fn variants(&self, day: u8) -> io::Result<Vec<String>> {
+        let mut variants = fs::read_dir(self.day_dir(day))?
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                match path.extension() {
+                    Some(ext) if ext == "txt" => Some(path.file_stem()?.to_str()?.to_owned()),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        variants.sort();
+        Ok(variants)
+    }
+}
+
+pub fn read_input(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("could not read {}: {e}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::InputStore;
+
+    #[test]
+    fn test_input_store() {
+        let root = env::temp_dir().join(format!("aoc-input-store-{}", std::process::id()));
+        let store = InputStore::new(&root, 2024);
+
+        assert_eq!(
+            store.path(3, "stress"),
+            root.join("2024").join("day03").join("stress.txt")
+        );
+        assert!(store.read(3, "real").is_err());
+
+        assert_eq!(
+            store.write(3, "real", "mul(1,2)").unwrap(),
+            store.path(3, "real")
+        );
+        fs::write(store.path(3, "example1"), "do()").unwrap();
+        fs::write(store.day_dir(3).join("notes.md"), "").unwrap();
+
+        assert_eq!(store.read(3, "real").unwrap(), "mul(1,2)");
+        assert_eq!(store.variants(3).unwrap(), vec!["example1", "real"]);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
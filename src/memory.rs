@@ -0,0 +1,166 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::{self, Display},
+};
+
+use serde::Serialize;
+
+#[derive(Clone, Copy)]
+struct Counters {
+    tracking: bool,
+    current: isize,
+    peak: isize,
+    allocations: usize,
+}
+
+impl Counters {
+    const IDLE: Self = Self {
+        tracking: false,
+        current: 0,
+        peak: 0,
+        allocations: 0,
+    };
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const { Cell::new(Counters::IDLE) };
+}
+
+fn record(grown: isize, allocated: bool) {
+    let _ = COUNTERS.try_with(|counters| {
+        let mut c = counters.get();
+        if c.tracking {
+            c.current += grown;
+            c.peak = c.peak.max(c.current);
+            c.allocations += allocated as usize;
+            counters.set(c);
+        }
+    });
+}
+
+/// Wraps the system allocator, counting the allocations made inside [`measure`] on the thread
+/// calling it. Install it with `#[global_allocator]` for the counts to be anything but zero.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize, true);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize, true);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(-(layout.size() as isize), false);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size as isize - layout.size() as isize, true);
+        }
+        new_ptr
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct MemoryStats {
+    /// Highest amount of heap memory held at once above what was held when measuring started.
+    pub peak_bytes: usize,
+    /// Number of allocations and reallocations.
+    pub allocations: usize,
+}
+
+/// Runs `f` while counting the heap allocations it makes on the current thread. Work that `f`
+/// hands to other threads is not counted.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, MemoryStats) {
+    COUNTERS.set(Counters {
+        tracking: true,
+        ..Counters::IDLE
+    });
+    let value = f();
+    let counters = COUNTERS.replace(Counters::IDLE);
+
+    (
+        value,
+        MemoryStats {
+            peak_bytes: counters.peak as usize,
+            allocations: counters.allocations,
+        },
+    )
+}
+
+pub struct Bytes(pub usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{size:.2} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+
+    use super::{measure, Bytes, CountingAllocator, MemoryStats};
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_measure() {
+        let (_, stats) = measure(|| {
+            let small = black_box(vec![0u8; 1000]);
+            drop(small);
+            black_box(vec![0u64; 1 << 17]).len()
+        });
+        assert_eq!(
+            stats,
+            MemoryStats {
+                peak_bytes: 1 << 20,
+                allocations: 2
+            }
+        );
+
+        let (_, stats) = measure(|| {
+            let mut grown = Vec::with_capacity(16);
+            grown.extend_from_slice(&[1u8; 16]);
+            grown.push(2);
+            black_box(grown)
+        });
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.peak_bytes, 32);
+
+        let (_, stats) = measure(|| black_box(3 + 4));
+        assert_eq!(stats.allocations, 0);
+    }
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.50 KiB");
+        assert_eq!(Bytes(3 << 20).to_string(), "3.00 MiB");
+    }
+}
@@ -0,0 +1,353 @@
+use std::any::Any;
+
+use itertools::Itertools;
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{
+    examples::Example,
+    solution::{Answer, Solution},
+    utils::{normalize::normalize_input, parsers::ParseError},
+};
+
+pub use crate::utils::normalize::Strictness;
+
+type Generator = fn(&str) -> Result<Box<dyn Any>, ParseError>;
+type Solver = fn(&dyn Any) -> Answer;
+type VariantSolvers = fn() -> Vec<VariantSolver>;
+type RandomInput = fn(&mut StdRng, usize) -> Option<String>;
+
+pub struct PartSolver {
+    pub part: u8,
+    pub(crate) solver: Solver,
+}
+
+impl PartSolver {
+    pub fn solve(&self, input: &dyn Any) -> Answer {
+        (self.solver)(input)
+    }
+}
+
+/// Name under which the solvers registered as `part1` and `part2` are compared to the variants.
+pub const MAIN_SOLVER: &str = "main";
+
+pub struct VariantSolver {
+    pub part: u8,
+    pub name: &'static str,
+    index: usize,
+    solver: fn(&dyn Any, usize) -> Answer,
+}
+
+impl VariantSolver {
+    pub fn solve(&self, input: &dyn Any) -> Answer {
+        (self.solver)(input, self.index)
+    }
+}
+
+pub struct DaySolver {
+    pub day: u8,
+    pub(crate) generator: Generator,
+    pub parts: &'static [PartSolver],
+    pub(crate) variants: VariantSolvers,
+    pub(crate) random_input: RandomInput,
+    pub examples: &'static [Example],
+}
+
+/// The answers of every solver of a part to the same input.
+pub struct VariantCheck {
+    pub part: u8,
+    pub answers: Vec<(&'static str, String)>,
+}
+
+impl VariantCheck {
+    pub fn agrees(&self) -> bool {
+        self.answers.iter().map(|(_, answer)| answer).all_equal()
+    }
+}
+
+pub struct ExampleCheck {
+    pub part: u8,
+    pub answer: String,
+    pub expected: &'static str,
+}
+
+impl ExampleCheck {
+    pub fn is_ok(&self) -> bool {
+        self.answer == self.expected
+    }
+}
+
+impl DaySolver {
+    pub fn generate(
+        &self,
+        input: &str,
+        strictness: Strictness,
+    ) -> Result<Box<dyn Any>, ParseError> {
+        (self.generator)(&normalize_input(input, strictness)?)
+    }
+
+    pub fn part(&self, part: u8) -> Option<&'static PartSolver> {
+        self.parts.iter().find(|p| p.part == part)
+    }
+
+    pub fn check_example(
+        &self,
+        example: &Example,
+        part: Option<u8>,
+    ) -> Result<Vec<ExampleCheck>, ParseError> {
+        let input = self.generate(example.input, Strictness::Strict)?;
+        Ok(self
+            .parts
+            .iter()
+            .filter(|p| part.is_none_or(|part| part == p.part))
+            .filter_map(|p| {
+                example.expected(p.part).map(|expected| ExampleCheck {
+                    part: p.part,
+                    answer: p.solve(input.as_ref()).to_string(),
+                    expected,
+                })
+            })
+            .collect())
+    }
+
+    pub fn variants(&self) -> Vec<VariantSolver> {
+        (self.variants)()
+    }
+
+    /// Generates the same input for the same seed and size.
+    pub fn random_input(&self, seed: u64, size: usize) -> Option<String> {
+        (self.random_input)(&mut StdRng::seed_from_u64(seed), size)
+    }
+
+    /// Runs the main solver and the variants named in `solvers`, every variant when it is empty,
+    /// of each part on the same input.
+    pub fn check_variants(
+        &self,
+        input: &str,
+        part: Option<u8>,
+        solvers: &[String],
+        strictness: Strictness,
+    ) -> Result<Vec<VariantCheck>, ParseError> {
+        let input = self.generate(input, strictness)?;
+        let variants = self.variants();
+        Ok(self
+            .parts
+            .iter()
+            .filter(|p| part.is_none_or(|part| part == p.part))
+            .map(|p| {
+                let main = (MAIN_SOLVER, p.solve(input.as_ref()).to_string());
+                let others = variants
+                    .iter()
+                    .filter(|v| v.part == p.part)
+                    .filter(|v| solvers.is_empty() || solvers.iter().any(|s| s == v.name))
+                    .map(|v| (v.name, v.solve(input.as_ref()).to_string()));
+                VariantCheck {
+                    part: p.part,
+                    answers: [main].into_iter().chain(others).collect(),
+                }
+            })
+            .collect())
+    }
+}
+
+pub(crate) fn generate<S: Solution>(input: &str) -> Result<Box<dyn Any>, ParseError> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input {
+    input
+        .downcast_ref()
+        .expect("input should come from the generator of the same day")
+}
+
+pub(crate) fn solve<S: Solution, const PART: u8>(input: &dyn Any) -> Answer {
+    let input = downcast::<S>(input);
+    match PART {
+        1 => S::part1(input),
+        2 => S::part2(input),
+        _ => unreachable!("days only have two parts"),
+    }
+}
+
+pub(crate) fn variants<S: Solution>() -> Vec<VariantSolver> {
+    S::VARIANTS
+        .iter()
+        .enumerate()
+        .map(|(index, variant)| VariantSolver {
+            part: variant.part,
+            name: variant.name,
+            index,
+            solver: |input, index| (S::VARIANTS[index].solve)(downcast::<S>(input)),
+        })
+        .collect()
+}
+
+/// Builds the [`DaySolver`] of a day module from its [`Solution`] and examples, for the `DAYS`
+/// registry of each year module.
+macro_rules! day {
+    ($day:literal => $module:ident :: $solution:ident) => {
+        $crate::runner::DaySolver {
+            day: $day,
+            generator: $crate::runner::generate::<$module::$solution>,
+            parts: &[
+                $crate::runner::PartSolver {
+                    part: 1,
+                    solver: $crate::runner::solve::<$module::$solution, 1>,
+                },
+                $crate::runner::PartSolver {
+                    part: 2,
+                    solver: $crate::runner::solve::<$module::$solution, 2>,
+                },
+            ],
+            variants: $crate::runner::variants::<$module::$solution>,
+            random_input: <$module::$solution as $crate::solution::Solution>::random_input,
+            examples: $module::EXAMPLES,
+        }
+    };
+}
+
+pub struct Year {
+    pub year: u32,
+    pub days: &'static [DaySolver],
+}
+
+impl Year {
+    pub fn find_day(&self, day: u8) -> Option<&'static DaySolver> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+macro_rules! year {
+    ($year:literal => $module:ident) => {
+        Year {
+            year: $year,
+            days: crate::$module::DAYS,
+        }
+    };
+}
+
+#[rustfmt::skip]
+pub static YEARS: &[Year] = &[
+    year!(2024 => y2024),
+];
+
+pub fn find_year(year: u32) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+pub fn latest_year() -> &'static Year {
+    YEARS
+        .last()
+        .expect("at least one year should be registered")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        let year = find_year(2024).unwrap();
+        assert_eq!(
+            year.days.iter().map(|d| d.day).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(latest_year().year, 2024);
+        assert!(find_year(2015).is_none());
+
+        let day = year.find_day(3).unwrap();
+        let input = day
+            .generate(
+                "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\r\n",
+                Strictness::Lenient,
+            )
+            .unwrap();
+
+        assert_eq!(
+            day.part(1).unwrap().solve(input.as_ref()).to_string(),
+            "161"
+        );
+        assert_eq!(day.part(2).unwrap().solve(input.as_ref()).to_string(), "48");
+        assert!(day.part(3).is_none());
+    }
+
+    #[test]
+    fn test_check_examples() {
+        let day = find_year(2024).unwrap().find_day(3).unwrap();
+        let checks = day.check_example(&day.examples[0], None).unwrap();
+        assert_eq!(checks.iter().map(|c| c.part).collect::<Vec<_>>(), vec![1]);
+        assert!(checks.iter().all(ExampleCheck::is_ok));
+
+        let checks = day.check_example(&day.examples[1], Some(2)).unwrap();
+        assert_eq!(checks.iter().map(|c| c.part).collect::<Vec<_>>(), vec![2]);
+        assert!(checks.iter().all(ExampleCheck::is_ok));
+    }
+
+    #[test]
+    fn test_check_variants() {
+        let day = find_year(2024).unwrap().find_day(2).unwrap();
+        assert_eq!(
+            day.variants()
+                .iter()
+                .map(|v| (v.part, v.name))
+                .collect::<Vec<_>>(),
+            vec![(2, "slow"), (1, "reference"), (2, "reference")]
+        );
+
+        let checks = day
+            .check_variants(day.examples[0].input, None, &[], Strictness::Strict)
+            .unwrap();
+        assert_eq!(
+            checks[0].answers,
+            vec![(MAIN_SOLVER, "2".to_owned()), ("reference", "2".to_owned())]
+        );
+        assert_eq!(
+            checks[1].answers,
+            vec![
+                (MAIN_SOLVER, "4".to_owned()),
+                ("slow", "4".to_owned()),
+                ("reference", "4".to_owned())
+            ]
+        );
+        assert!(checks.iter().all(VariantCheck::agrees));
+
+        let checks = day
+            .check_variants(
+                day.examples[0].input,
+                Some(2),
+                &["slow".to_owned()],
+                Strictness::Strict,
+            )
+            .unwrap();
+        assert_eq!(
+            checks[0].answers,
+            vec![(MAIN_SOLVER, "4".to_owned()), ("slow", "4".to_owned())]
+        );
+
+        let disagreement = VariantCheck {
+            part: 1,
+            answers: vec![(MAIN_SOLVER, "1".to_owned()), ("slow", "2".to_owned())],
+        };
+        assert!(!disagreement.agrees());
+    }
+
+    #[test]
+    fn test_random_inputs() {
+        for day in find_year(2024).unwrap().days {
+            let input = day.random_input(7, 30).unwrap();
+            assert_eq!(day.random_input(7, 30).unwrap(), input);
+            assert_ne!(day.random_input(8, 30).unwrap(), input);
+
+            for seed in 0..20 {
+                let input = day.random_input(seed, 30).unwrap();
+                let checks = day
+                    .check_variants(&input, None, &[], Strictness::Strict)
+                    .unwrap();
+                assert!(
+                    checks.iter().all(VariantCheck::agrees),
+                    "day {} disagrees on seed {seed}",
+                    day.day
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use crate::inputs::{InputStore, DEFAULT_VARIANT};
+
+/// The event declared to cargo-aoc by `aoc_lib!` in `lib.rs`.
+const CARGO_AOC_YEAR: u32 = 2024;
+const TEMPLATE_DAY: &str = "dayN";
+
+fn module_name(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+fn solution_name(day: u8) -> String {
+    format!("Day{day:02}")
+}
+
+/// Names the [`Solution`](crate::solution::Solution) of the template after the day, and points
+/// its cargo-aoc attributes at the day. Days of other years than the one cargo-aoc knows about
+/// lose the attributes, as their days would clash with its own.
+pub fn render_template(template: &str, year: u32, day: u8) -> String {
+    let template = if year == CARGO_AOC_YEAR {
+        template
+            .replace(&format!("({TEMPLATE_DAY},"), &format!("(day{day},"))
+            .replace(&format!("({TEMPLATE_DAY})"), &format!("(day{day})"))
+    } else {
+        template
+            .lines()
+            .filter(|line| !line.starts_with("#[aoc"))
+            .map(|line| format!("{line}\n"))
+            .collect()
+    };
+    template.replace("DayN", &solution_name(day))
+}
+
+fn year_module_name(year: u32) -> String {
+    format!("y{year}")
+}
+
+/// Inserts `line` before the first entry with a later key, or after the last entry when every
+/// entry has an earlier key. Returns `None` when the key already has an entry.
+fn insert_sorted(
+    source: &str,
+    line: &str,
+    is_entry: impl Fn(&str) -> Option<u32>,
+    key: u32,
+) -> Option<String> {
+    let lines = source.lines().collect::<Vec<_>>();
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| is_entry(l).map(|k| (i, k)))
+        .collect::<Vec<_>>();
+
+    if entries.iter().any(|(_, k)| *k == key) {
+        return None;
+    }
+
+    let position = entries
+        .iter()
+        .find(|(_, k)| *k > key)
+        .map(|(i, _)| *i)
+        .or_else(|| entries.last().map(|(i, _)| i + 1))?;
+
+    let mut lines = lines;
+    lines.insert(position, line);
+    Some(lines.join("\n") + "\n")
+}
+
+fn parse_module(line: &str, prefix: &str) -> Option<u32> {
+    line.strip_prefix("pub mod ")?
+        .strip_prefix(prefix)?
+        .strip_suffix(';')?
+        .parse()
+        .ok()
+}
+
+fn parse_macro_entry(line: &str, name: &str) -> Option<u32> {
+    line.trim_start()
+        .strip_prefix(name)?
+        .strip_prefix("!(")?
+        .split_once(" =>")?
+        .0
+        .parse()
+        .ok()
+}
+
+fn day_entry(day: u8) -> String {
+    format!(
+        "    day!({day} => {}::{}),",
+        module_name(day),
+        solution_name(day)
+    )
+}
+
+/// Adds `pub mod dayNN;` and its `DAYS` entry to the `mod.rs` of a year, keeping both sorted.
+pub fn add_day(year_module: &str, day: u8) -> Option<String> {
+    let with_module = insert_sorted(
+        year_module,
+        &format!("pub mod {};", module_name(day)),
+        |l| parse_module(l, "day"),
+        day as u32,
+    )?;
+    insert_sorted(
+        &with_module,
+        &day_entry(day),
+        |l| parse_macro_entry(l, "day"),
+        day as u32,
+    )
+}
+
+/// Creates the `mod.rs` of a year whose first module is `day`.
+pub fn new_year_module(day: u8) -> String {
+    format!(
+        "pub mod {};\n\npub static DAYS: &[crate::runner::DaySolver] = &[\n{}\n];\n",
+        module_name(day),
+        day_entry(day)
+    )
+}
+
+/// Adds `pub mod yYYYY;` to `lib.rs`, keeping the year modules sorted.
+pub fn add_year_module(lib: &str, year: u32) -> Option<String> {
+    insert_sorted(
+        lib,
+        &format!("pub mod {};", year_module_name(year)),
+        |l| parse_module(l, "y"),
+        year,
+    )
+}
+
+/// Adds the year to the `YEARS` registry of `runner.rs`, keeping it sorted.
+pub fn add_year_entry(runner: &str, year: u32) -> Option<String> {
+    insert_sorted(
+        runner,
+        &format!("    year!({year} => {}),", year_module_name(year)),
+        |l| parse_macro_entry(l, "year"),
+        year,
+    )
+}
+
+fn already_registered(what: String) -> io::Error {
+    io::Error::new(
+        ErrorKind::AlreadyExists,
+        format!("{what} is already registered"),
+    )
+}
+
+fn create_new(path: &Path, content: &str) -> io::Result<()> {
+    if path.exists() {
+        return Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} already exists", path.display()),
+        ));
+    }
+    fs::write(path, content)
+}
+
+/// Creates `src/yYYYY/dayNN.rs` from the template, registers it (and its year, when new) and
+/// creates its empty input files, returning the paths that were written. Nothing is written
+/// unless `crate_root` holds a `Cargo.toml` and a `src` directory.
+pub fn scaffold_day(
+    crate_root: &Path,
+    inputs: &InputStore,
+    year: u32,
+    day: u8,
+) -> io::Result<Vec<PathBuf>> {
+    let src = crate_root.join("src");
+    if !crate_root.join("Cargo.toml").is_file() || !src.is_dir() {
+        return Err(io::Error::new(
+            ErrorKind::NotFound,
+            format!(
+                "{} is not a crate root, it should hold Cargo.toml and src/",
+                crate_root.display()
+            ),
+        ));
+    }
+    let year_dir = src.join(year_module_name(year));
+    let year_module = year_dir.join("mod.rs");
+    let module = year_dir.join(format!("{}.rs", module_name(day)));
+
+    let template = fs::read_to_string(src.join("template.rs"))?;
+
+    let mut updates = vec![];
+    if year_module.exists() {
+        let updated = add_day(&fs::read_to_string(&year_module)?, day)
+            .ok_or_else(|| already_registered(format!("day {day} of {year}")))?;
+        updates.push((year_module, updated));
+    } else {
+        let lib = src.join("lib.rs");
+        let runner = src.join("runner.rs");
+        let updated_lib = add_year_module(&fs::read_to_string(&lib)?, year)
+            .ok_or_else(|| already_registered(format!("module of {year}")))?;
+        let updated_runner = add_year_entry(&fs::read_to_string(&runner)?, year)
+            .ok_or_else(|| already_registered(format!("year {year}")))?;
+
+        fs::create_dir_all(&year_dir)?;
+        updates.push((year_module, new_year_module(day)));
+        updates.push((lib, updated_lib));
+        updates.push((runner, updated_runner));
+    }
+
+    create_new(&module, &render_template(&template, year, day))?;
+    let mut written = vec![module];
+    for (path, content) in updates {
+        fs::write(&path, content)?;
+        written.push(path);
+    }
+
+    fs::create_dir_all(inputs.day_dir(day))?;
+    for variant in [DEFAULT_VARIANT, "example1"] {
+        let path = inputs.path(day, variant);
+        if !path.exists() {
+            fs::write(&path, "")?;
+            written.push(path);
+        }
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::ErrorKind};
+
+    use super::{
+        add_day, add_year_entry, add_year_module, new_year_module, render_template, scaffold_day,
+    };
+    use crate::inputs::InputStore;
+
+    #[test]
+    fn test_add_day() {
+        let module = "pub mod day01;\npub mod day04;\n\npub static DAYS: &[DaySolver] = &[\n    day!(1 => day01::Day01),\n    day!(4 => day04::Day04),\n];\n";
+
+        assert_eq!(
+            add_day(module, 2).unwrap(),
+            "pub mod day01;\npub mod day02;\npub mod day04;\n\npub static DAYS: &[DaySolver] = &[\n    day!(1 => day01::Day01),\n    day!(2 => day02::Day02),\n    day!(4 => day04::Day04),\n];\n"
+        );
+        assert_eq!(
+            add_day(module, 12).unwrap(),
+            "pub mod day01;\npub mod day04;\npub mod day12;\n\npub static DAYS: &[DaySolver] = &[\n    day!(1 => day01::Day01),\n    day!(4 => day04::Day04),\n    day!(12 => day12::Day12),\n];\n"
+        );
+        assert_eq!(add_day(module, 4), None);
+    }
+
+    #[test]
+    fn test_render_template() {
+        let rendered = render_template(include_str!("template.rs"), 2024, 7);
+        assert!(rendered.contains("pub struct Day07;\n"));
+        assert!(rendered.contains("impl Solution for Day07 {"));
+        assert!(rendered.contains("#[aoc_generator(day7)]\n"));
+        assert!(rendered.contains("#[aoc(day7, part2)]\n"));
+        assert!(!rendered.contains("DayN") && !rendered.contains("dayN"));
+
+        let rendered = render_template(include_str!("template.rs"), 2023, 7);
+        assert!(rendered.contains("pub struct Day07;\n"));
+        assert!(!rendered.contains("#[aoc"));
+    }
+
+    #[test]
+    fn test_add_year() {
+        assert_eq!(
+            new_year_module(3),
+            "pub mod day03;\n\npub static DAYS: &[crate::runner::DaySolver] = &[\n    day!(3 => day03::Day03),\n];\n"
+        );
+
+        let lib = "#[macro_use]\npub mod runner;\npub mod timing;\npub mod y2024;\n";
+        assert_eq!(
+            add_year_module(lib, 2023).unwrap(),
+            "#[macro_use]\npub mod runner;\npub mod timing;\npub mod y2023;\npub mod y2024;\n"
+        );
+        assert_eq!(add_year_module(lib, 2024), None);
+
+        let runner = "macro_rules! year {\n    ($year:literal => $module:ident) => {};\n}\n\npub static YEARS: &[Year] = &[\n    year!(2024 => y2024),\n];\n";
+        assert_eq!(
+            add_year_entry(runner, 2025).unwrap(),
+            "macro_rules! year {\n    ($year:literal => $module:ident) => {};\n}\n\npub static YEARS: &[Year] = &[\n    year!(2024 => y2024),\n    year!(2025 => y2025),\n];\n"
+        );
+    }
+
+    #[test]
+    fn test_scaffold_outside_crate_root() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        let inputs = InputStore::new(root.join("inputs"), 2024);
+
+        let error = scaffold_day(&root, &inputs, 2024, 5).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+        assert_eq!(fs::read_dir(&root).unwrap().count(), 1);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt::{self, Display};
+
+use ndarray::Array2;
+use rand::rngs::StdRng;
+
+use crate::utils::{grid_utils::render_map, ocr, parsers::ParseError};
+
+/// The answer of a part, displayed the way it is submitted. Letter art is displayed as the letters
+/// it spells, or as drawn by [`render_map`] when they cannot
+/// all be recognized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+    Art(Array2<bool>),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Art(art) => match ocr::recognize(art) {
+                Ok(letters) => write!(f, "{letters}"),
+                Err(_) => write!(f, "{}", render_map(art)),
+            },
+        }
+    }
+}
+
+macro_rules! integer_answer {
+    ($variant:ident: $($ty:ty),*) => {
+        $(impl From<$ty> for Answer {
+            fn from(n: $ty) -> Self {
+                Answer::$variant(n.try_into().unwrap())
+            }
+        })*
+    };
+}
+
+integer_answer!(Unsigned: u8, u16, u32, u64, u128, usize);
+integer_answer!(Signed: i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
+
+impl From<Array2<bool>> for Answer {
+    fn from(art: Array2<bool>) -> Self {
+        Answer::Art(art)
+    }
+}
+
+/// An alternative implementation of a part, such as the naive version an optimized solver is
+/// checked against.
+pub struct Variant<I: 'static> {
+    pub name: &'static str,
+    pub part: u8,
+    pub solve: fn(&I) -> Answer,
+}
+
+/// A day of the event: how its input is parsed and how each part is solved from it.
+pub trait Solution {
+    type Input: 'static;
+
+    /// Alternative solvers, compared against [`Solution::part1`] and [`Solution::part2`] by the
+    /// differential runner.
+    const VARIANTS: &'static [Variant<Self::Input>] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Writes a random valid input with about `size` lines, or rows for grids, for stress tests
+    /// and benchmarks. Days that cannot generate inputs return `None`.
+    fn random_input(_rng: &mut StdRng, _size: usize) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::array;
+
+    use super::Answer;
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(u128::MAX), Answer::Unsigned(u128::MAX));
+        assert_eq!(Answer::from(-3i32).to_string(), "-3");
+        assert_eq!(Answer::from(i128::MIN), Answer::Signed(i128::MIN));
+        assert_eq!(Answer::from("1,2,3").to_string(), "1,2,3");
+        assert_eq!(
+            Answer::from(array![[true, false, true], [false, true, false]]).to_string(),
+            "##  ##\n  ##  "
+        );
+
+        let letters = array![
+            [true, true, true, true],
+            [true, false, false, false],
+            [true, true, true, false],
+            [true, false, false, false],
+            [true, false, false, false],
+            [true, false, false, false],
+        ];
+        assert_eq!(Answer::from(letters).to_string(), "F");
+    }
+}
@@ -0,0 +1,316 @@
+use std::fmt::{self, Display};
+
+use crate::answers::{AnswerStore, Refusal};
+
+pub const DEFAULT_ENDPOINT: &str = "https://adventofcode.com";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooLow,
+    TooHigh,
+    Wrong,
+    RateLimited { wait: Option<String> },
+    AlreadyCompleted,
+    Unrecognized,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, {wait} left to wait")
+            }
+            Outcome::RateLimited { wait: None } => write!(f, "rate limited"),
+            Outcome::AlreadyCompleted => write!(f, "part already completed or locked"),
+            Outcome::Unrecognized => write!(f, "unrecognized response"),
+        }
+    }
+}
+
+/// Reads the outcome out of the HTML page returned after posting an answer.
+pub fn parse_response(body: &str) -> Outcome {
+    if body.contains("That's the right answer") {
+        Outcome::Correct
+    } else if body.contains("That's not the right answer") {
+        if body.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if body.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else {
+            Outcome::Wrong
+        }
+    } else if body.contains("You gave an answer too recently") {
+        let wait = body
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| wait.to_owned());
+        Outcome::RateLimited { wait }
+    } else if body.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadyCompleted
+    } else {
+        Outcome::Unrecognized
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Http(String),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitted, {refusal}"),
+            SubmitError::Http(e) => write!(f, "submission failed: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+pub struct Client {
+    endpoint: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(endpoint: &str, session: &str) -> Self {
+        Self {
+            endpoint: endpoint.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!(
+                    env!("CARGO_PKG_NAME"),
+                    "/",
+                    env!("CARGO_PKG_VERSION")
+                ))
+                .build(),
+        }
+    }
+
+    pub fn post_answer(
+        &self,
+        year: u32,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Outcome, SubmitError> {
+        let url = format!("{}/{year}/day/{day}/answer", self.endpoint);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| SubmitError::Http(e.to_string()))?;
+        let body = response
+            .into_string()
+            .map_err(|e| SubmitError::Http(e.to_string()))?;
+
+        Ok(parse_response(&body))
+    }
+}
+
+/// Submits an answer unless the store already tells it cannot be right, recording the outcome.
+pub fn submit(
+    client: &Client,
+    store: &mut AnswerStore,
+    (year, day, variant): (u32, u8, &str),
+    part: u8,
+    answer: &str,
+) -> Result<Outcome, SubmitError> {
+    if let Some(known) = store.variant(year, day, variant) {
+        known
+            .check_submission(part, answer)
+            .map_err(SubmitError::Refused)?;
+    }
+
+    let outcome = client.post_answer(year, day, part, answer)?;
+
+    // Only verdicts are recorded, so a submission without one adds no empty entry.
+    match outcome {
+        Outcome::Correct => store
+            .variant_mut(year, day, variant)
+            .set(part, answer.to_owned()),
+        Outcome::TooLow => store
+            .variant_mut(year, day, variant)
+            .rejections_mut(part)
+            .reject(answer, true, false),
+        Outcome::TooHigh => store
+            .variant_mut(year, day, variant)
+            .rejections_mut(part)
+            .reject(answer, false, true),
+        Outcome::Wrong => store
+            .variant_mut(year, day, variant)
+            .rejections_mut(part)
+            .reject(answer, false, false),
+        Outcome::RateLimited { .. } | Outcome::AlreadyCompleted | Outcome::Unrecognized => {}
+    }
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use super::{parse_response, submit, Client, Outcome, SubmitError};
+    use crate::answers::{AnswerStore, Refusal};
+
+    const WRONG_LOW: &str = "<article><p>That's not the right answer; your answer is too low. \
+        If you're stuck, make sure you're using the full input data.</p></article>";
+    const WRONG_HIGH: &str =
+        "<article><p>That's not the right answer; your answer is too high.</p></article>";
+    const RIGHT: &str = "<article><p>That's the right answer! You are one gold star closer to \
+        finding the Chief Historian.</p></article>";
+    const RATE_LIMITED: &str = "<article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again.  You have 4m 37s left to wait.</p></article>";
+
+    /// Serves the given bodies to successive requests, sending back each request line and body.
+    fn mock_server(responses: Vec<&'static str>) -> (String, mpsc::Receiver<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for body in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+                let mut form = vec![0; content_length];
+                reader.read_exact(&mut form).unwrap();
+                sender
+                    .send((
+                        request_line.trim().to_owned(),
+                        String::from_utf8(form).unwrap(),
+                    ))
+                    .unwrap();
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        (address, receiver)
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(parse_response(RIGHT), Outcome::Correct);
+        assert_eq!(parse_response(WRONG_LOW), Outcome::TooLow);
+        assert_eq!(parse_response(WRONG_HIGH), Outcome::TooHigh);
+        assert_eq!(
+            parse_response(RATE_LIMITED),
+            Outcome::RateLimited {
+                wait: Some("4m 37s".to_owned())
+            }
+        );
+        assert_eq!(parse_response("<html></html>"), Outcome::Unrecognized);
+    }
+
+    #[test]
+    fn test_submit_against_mock_server() {
+        let (endpoint, requests) = mock_server(vec![WRONG_LOW, WRONG_HIGH, RATE_LIMITED, RIGHT]);
+        let client = Client::new(&endpoint, "cookie");
+        let mut store = AnswerStore::default();
+        let key = (2024, 3, "real");
+
+        assert_eq!(
+            submit(&client, &mut store, key, 1, "100").unwrap(),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            requests.recv().unwrap(),
+            (
+                "POST /2024/day/3/answer HTTP/1.1".to_owned(),
+                "level=1&answer=100".to_owned()
+            )
+        );
+        assert_eq!(
+            submit(&client, &mut store, key, 1, "200").unwrap(),
+            Outcome::TooHigh
+        );
+
+        for (answer, refusal) in [
+            ("100", Refusal::KnownWrong),
+            (
+                "90",
+                Refusal::NotAboveLowBound {
+                    too_low: "100".to_owned(),
+                },
+            ),
+            (
+                "250",
+                Refusal::NotBelowHighBound {
+                    too_high: "200".to_owned(),
+                },
+            ),
+        ] {
+            match submit(&client, &mut store, key, 1, answer) {
+                Err(SubmitError::Refused(r)) => assert_eq!(r, refusal),
+                other => panic!("{answer} should have been refused, got {other:?}"),
+            }
+        }
+
+        assert!(matches!(
+            submit(&client, &mut store, key, 1, "150").unwrap(),
+            Outcome::RateLimited { .. }
+        ));
+        assert_eq!(
+            submit(&client, &mut store, key, 1, "150").unwrap(),
+            Outcome::Correct
+        );
+        assert_eq!(store.get(2024, 3, "real", 1), Some("150"));
+
+        let known = store.variant(2024, 3, "real").unwrap();
+        assert_eq!(known.rejections(1).unwrap().wrong, vec!["100", "200"]);
+        assert!(matches!(
+            submit(&client, &mut store, key, 1, "151"),
+            Err(SubmitError::Refused(Refusal::AlreadySolved { .. }))
+        ));
+        assert_eq!(requests.iter().count(), 3);
+    }
+
+    #[test]
+    fn test_submit_without_verdict() {
+        let (endpoint, _requests) = mock_server(vec![RATE_LIMITED]);
+        let client = Client::new(&endpoint, "cookie");
+        let mut store = AnswerStore::default();
+
+        assert!(matches!(
+            submit(&client, &mut store, (2024, 3, "real"), 1, "150").unwrap(),
+            Outcome::RateLimited { .. }
+        ));
+        assert!(store.variant(2024, 3, "real").is_none());
+    }
+}
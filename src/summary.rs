@@ -0,0 +1,433 @@
+use std::{
+    any::Any,
+    fmt::{self, Display},
+    io,
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use itertools::Itertools;
+use rayon::ThreadPoolBuilder;
+
+use crate::{
+    runner::{DaySolver, Strictness},
+    timing::{render_table, Step},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Status {
+    Ok,
+    MissingInput(String),
+    ParseError(String),
+    Panicked(String),
+    TimedOut,
+    /// An earlier step of the day timed out, so this part never ran.
+    NotRun,
+}
+
+impl Status {
+    pub fn is_ok(&self) -> bool {
+        *self == Status::Ok
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::MissingInput(_) => "no input",
+            Status::ParseError(_) => "parse error",
+            Status::Panicked(_) => "panicked",
+            Status::TimedOut => "timed out",
+            Status::NotRun => "not run",
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::MissingInput(e) | Status::ParseError(e) | Status::Panicked(e) => {
+                write!(f, "{}: {e}", self.label())
+            }
+            _ => write!(f, "{}", self.label()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub time: Option<Duration>,
+    pub status: Status,
+}
+
+impl Cell {
+    fn failed(part: u8, status: Status) -> Self {
+        Self {
+            part,
+            answer: None,
+            time: None,
+            status,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub day: u8,
+    pub cells: Vec<Cell>,
+}
+
+enum Event {
+    Started(usize, Step),
+    ParseFailed(usize, Status),
+    Solved(usize, Cell),
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map_or_else(|| "unknown panic".to_owned(), |m| (*m).to_owned()),
+    }
+}
+
+fn run_day(index: usize, day: &DaySolver, input: &str, events: mpsc::Sender<Event>) {
+    let _ = events.send(Event::Started(index, Step::Parse));
+    let generated = panic::catch_unwind(|| day.generate(input, Strictness::Lenient));
+    let generated = match generated {
+        Ok(Ok(generated)) => generated,
+        Ok(Err(e)) => {
+            let _ = events.send(Event::ParseFailed(index, Status::ParseError(e.to_string())));
+            return;
+        }
+        Err(payload) => {
+            let message = format!("while parsing: {}", panic_message(payload));
+            let _ = events.send(Event::ParseFailed(index, Status::Panicked(message)));
+            return;
+        }
+    };
+
+    for part in day.parts {
+        let _ = events.send(Event::Started(index, Step::Part(part.part)));
+        let start = Instant::now();
+        let answer = panic::catch_unwind(AssertUnwindSafe(|| {
+            part.solve(generated.as_ref()).to_string()
+        }));
+        let time = start.elapsed();
+        let cell = match answer {
+            Ok(answer) => Cell {
+                part: part.part,
+                answer: Some(answer),
+                time: Some(time),
+                status: Status::Ok,
+            },
+            Err(payload) => Cell::failed(part.part, Status::Panicked(panic_message(payload))),
+        };
+        let _ = events.send(Event::Solved(index, cell));
+    }
+}
+
+struct Progress {
+    day: &'static DaySolver,
+    cells: Vec<Cell>,
+    running: Option<(Step, Instant)>,
+    done: bool,
+}
+
+impl Progress {
+    fn fail_remaining(&mut self, status: Status) {
+        for part in &self.day.parts[self.cells.len()..] {
+            self.cells.push(Cell::failed(part.part, status.clone()));
+        }
+        self.done = true;
+    }
+
+    fn time_out(&mut self) {
+        if let Some((Step::Part(part), _)) = self.running {
+            self.cells.push(Cell::failed(part, Status::TimedOut));
+            self.fail_remaining(Status::NotRun);
+        } else {
+            self.fail_remaining(Status::TimedOut);
+        }
+    }
+}
+
+const THREAD_PREFIX: &str = "day-runner-";
+
+/// Stops printing the panics of the threads [`run_all`] runs the days on, as they are reported in
+/// the summary instead, and sends every other panic to the hook installed before. The hook is
+/// never removed, because the threads of timed out days keep running and may still panic after
+/// `run_all` returns.
+pub fn silence_day_panics() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let name = thread::current().name().map(str::to_owned);
+        if !name.is_some_and(|name| name.starts_with(THREAD_PREFIX)) {
+            previous(info);
+        }
+    }));
+}
+
+/// Runs every day on its own thread of a rayon pool, catching panics and giving up on any step
+/// that takes longer than `timeout`. Threads of timed out steps are detached: they keep running
+/// in the background, and their results are dropped.
+pub fn run_all(
+    days: Vec<(&'static DaySolver, io::Result<String>)>,
+    timeout: Duration,
+) -> Vec<DayReport> {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(days.len().max(1))
+        .thread_name(|i| format!("{THREAD_PREFIX}{i}"))
+        .build()
+        .expect("thread pool should be created");
+    let (sender, events) = mpsc::channel();
+
+    let mut progress = vec![];
+    for (index, (day, input)) in days.into_iter().enumerate() {
+        let mut day_progress = Progress {
+            day,
+            cells: vec![],
+            running: None,
+            done: false,
+        };
+        match input {
+            Ok(input) => {
+                let sender = sender.clone();
+                pool.spawn(move || run_day(index, day, &input, sender));
+            }
+            Err(e) => day_progress.fail_remaining(Status::MissingInput(e.to_string())),
+        }
+        progress.push(day_progress);
+    }
+    drop(sender);
+
+    while progress.iter().any(|p| !p.done) {
+        let deadline = progress
+            .iter()
+            .filter(|p| !p.done)
+            .filter_map(|p| p.running.map(|(_, start)| start + timeout))
+            .min();
+        let event = match deadline {
+            Some(deadline) => {
+                events.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => events.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match event {
+            Ok(Event::Started(index, step)) => {
+                progress[index].running = Some((step, Instant::now()));
+            }
+            Ok(Event::ParseFailed(index, status)) if !progress[index].done => {
+                progress[index].fail_remaining(status);
+            }
+            Ok(Event::Solved(index, cell)) if !progress[index].done => {
+                let day = &mut progress[index];
+                day.cells.push(cell);
+                day.running = None;
+                day.done = day.cells.len() == day.day.parts.len();
+            }
+            Ok(_) => {}
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                for day in progress.iter_mut().filter(|p| !p.done) {
+                    if day
+                        .running
+                        .is_some_and(|(_, start)| now.duration_since(start) >= timeout)
+                    {
+                        day.time_out();
+                    }
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                for day in progress.iter_mut().filter(|p| !p.done) {
+                    day.fail_remaining(Status::NotRun);
+                }
+            }
+        }
+    }
+
+    progress
+        .into_iter()
+        .map(|p| DayReport {
+            day: p.day.day,
+            cells: p.cells,
+        })
+        .collect()
+}
+
+fn answer_cell(cell: &Cell) -> String {
+    match &cell.answer {
+        Some(answer) if answer.contains('\n') => "(art)".to_owned(),
+        Some(answer) => answer.clone(),
+        None => String::new(),
+    }
+}
+
+/// Renders the reports as a grid with a row per day and the answer, time and status of each
+/// part, followed by the details of every failed cell.
+pub fn render(reports: &[DayReport]) -> String {
+    let parts = reports
+        .iter()
+        .flat_map(|r| r.cells.iter().map(|c| c.part))
+        .unique()
+        .sorted()
+        .collect_vec();
+
+    let mut headers = vec!["Day".to_owned()];
+    for part in &parts {
+        headers.extend([
+            format!("Part {part}"),
+            "Time".to_owned(),
+            "Status".to_owned(),
+        ]);
+    }
+
+    let rows = reports
+        .iter()
+        .map(|report| {
+            let mut row = vec![report.day.to_string()];
+            for part in &parts {
+                match report.cells.iter().find(|c| c.part == *part) {
+                    Some(cell) => row.extend([
+                        answer_cell(cell),
+                        cell.time.map(|t| format!("{t:.2?}")).unwrap_or_default(),
+                        cell.status.label().to_owned(),
+                    ]),
+                    None => row.extend([String::new(), String::new(), String::new()]),
+                }
+            }
+            row
+        })
+        .collect_vec();
+
+    let mut out = render_table(&headers.iter().map(String::as_str).collect_vec(), &rows);
+    for report in reports {
+        let statuses = report
+            .cells
+            .iter()
+            .map(|c| &c.status)
+            .unique()
+            .collect_vec();
+        match statuses[..] {
+            [status] if !status.is_ok() => out += &format!("Day {}: {status}\n", report.day),
+            _ => {
+                for cell in report.cells.iter().filter(|c| !c.status.is_ok()) {
+                    out += &format!("Day {} - Part {}: {}\n", report.day, cell.part, cell.status);
+                }
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io, time::Duration};
+
+    use super::{render, run_all, Status};
+    use crate::runner::DaySolver;
+
+    const TIMEOUT: Duration = Duration::from_millis(300);
+
+    mod flaky {
+        use std::thread;
+
+        use crate::{
+            examples::Example,
+            solution::{Answer, Solution},
+            utils::parsers::ParseError,
+        };
+
+        pub const EXAMPLES: &[Example] = &[];
+
+        pub struct Flaky;
+
+        impl Solution for Flaky {
+            type Input = String;
+
+            fn parse(input: &str) -> Result<String, ParseError> {
+                if input == "panic" {
+                    panic!("cannot parse");
+                }
+                Ok(input.to_owned())
+            }
+
+            fn part1(input: &String) -> Answer {
+                if input == "slow" {
+                    thread::sleep(super::TIMEOUT * 2);
+                }
+                input.len().into()
+            }
+
+            fn part2(_input: &String) -> Answer {
+                todo!()
+            }
+        }
+    }
+
+    static DAYS: &[DaySolver] = &[
+        day!(1 => flaky::Flaky),
+        day!(2 => flaky::Flaky),
+        day!(3 => flaky::Flaky),
+        day!(4 => flaky::Flaky),
+    ];
+
+    #[test]
+    fn test_run_all() {
+        let inputs = ["abc", "slow", "panic"].map(|i| Ok(i.to_owned()));
+        let days = DAYS
+            .iter()
+            .zip(
+                inputs
+                    .into_iter()
+                    .chain([Err(io::ErrorKind::NotFound.into())]),
+            )
+            .collect();
+
+        let reports = run_all(days, TIMEOUT);
+        let statuses = reports
+            .iter()
+            .map(|r| r.cells.iter().map(|c| c.status.clone()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(reports[0].cells[0].answer.as_deref(), Some("3"));
+        assert_eq!(
+            statuses,
+            vec![
+                vec![
+                    Status::Ok,
+                    Status::Panicked("not yet implemented".to_owned())
+                ],
+                vec![Status::TimedOut, Status::NotRun],
+                vec![Status::Panicked("while parsing: cannot parse".to_owned()); 2],
+                vec![Status::MissingInput("entity not found".to_owned()); 2],
+            ]
+        );
+
+        let table = render(&reports);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert!(lines[0].starts_with("Day  Part 1  Time"));
+        assert!(lines[0].ends_with("Status     Part 2  Time  Status"));
+        assert!(
+            lines[1].starts_with("1    3 ")
+                && lines[1].ends_with(" ok                       panicked")
+        );
+        assert!(
+            lines[2].starts_with("2 ") && lines[2].ends_with(" timed out                not run")
+        );
+        assert_eq!(
+            &lines[5..8],
+            [
+                "Day 1 - Part 2: panicked: not yet implemented",
+                "Day 2 - Part 1: timed out",
+                "Day 2 - Part 2: not run",
+            ]
+        );
+        assert_eq!(lines[8], "Day 3: panicked: while parsing: cannot parse");
+    }
+}
@@ -1,9 +1,13 @@
-use cgmath::vec2;
 use chumsky::{prelude::*, text::newline};
-use itertools::Itertools;
 use ndarray::Array2;
 
-use crate::utils::array_from_vector;
+use crate::{
+    solution::{Answer, Solution},
+    utils::{
+        array_from_vector,
+        parsers::{ParseError, ParseInput},
+    },
+};
 
 type Input = Array2<bool>;
 
@@ -18,27 +22,37 @@ fn parser() -> impl Parser<char, Input, Error = Simple<char>> {
         .then_ignore(end())
 }
 
-#[aoc_generator(day11)]
-pub fn input_generator(input: &str) -> Input {
-    parser().parse(input).unwrap()
+#[aoc_generator(dayN)]
+pub fn input_generator(input: &str) -> Result<Input, ParseError> {
+    parser().parse_input(input)
 }
 
-#[aoc(day11, part1)]
-pub fn solve_part1(input: &Input) -> usize {
+#[aoc(dayN, part1)]
+pub fn solve_part1(_input: &Input) -> usize {
     todo!("Implement solver");
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+#[aoc(dayN, part2)]
+pub fn solve_part2(_input: &Input) -> usize {
+    todo!("Implement solver");
+}
+
+pub struct DayN;
 
-    #[test]
-    fn test_part1() {
-        let i = "";
+impl Solution for DayN {
+    type Input = Input;
 
-        let i = input_generator(i);
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input).into()
+    }
 
-        assert_eq!(solve_part1(&i), 374);
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input).into()
     }
 }
 
+examples! {}
@@ -0,0 +1,317 @@
+use std::{
+    fmt::{self, Display, Write},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use clap::ValueEnum;
+use itertools::Itertools;
+use serde::{Serialize, Serializer};
+
+use crate::{
+    memory::{measure, Bytes, MemoryStats},
+    runner::{DaySolver, Strictness},
+    utils::parsers::ParseError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    pub runs: usize,
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+}
+
+fn as_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Self {
+            runs: samples.len(),
+            min: samples[0],
+            median,
+        }
+    }
+}
+
+pub fn time_repeated<T>(repeat: usize, mut f: impl FnMut() -> T) -> (T, Stats) {
+    let mut samples = Vec::with_capacity(repeat);
+    let mut result = None;
+    for _ in 0..repeat.max(1) {
+        let start = Instant::now();
+        let value = black_box(f());
+        samples.push(start.elapsed());
+        result = Some(value);
+    }
+    (result.unwrap(), Stats::from_samples(samples))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Parse,
+    Part(u8),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+impl Serialize for Step {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Timing {
+    pub day: u8,
+    pub step: Step,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    #[serde(flatten)]
+    pub stats: Stats,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryStats>,
+}
+
+/// Times the generator and the solvers of a day. With `memory`, each step is also run once more
+/// on its own to count its allocations.
+pub fn bench_day(
+    day: &DaySolver,
+    input: &str,
+    part: Option<u8>,
+    repeat: usize,
+    memory: bool,
+) -> Result<Vec<Timing>, ParseError> {
+    let (generated, stats) = time_repeated(repeat, || day.generate(input, Strictness::Lenient));
+    let generated = generated?;
+    let memory_of = |step: &dyn Fn()| memory.then(|| measure(step).1);
+
+    let mut timings = vec![Timing {
+        day: day.day,
+        step: Step::Parse,
+        answer: None,
+        stats,
+        memory: memory_of(&|| drop(black_box(day.generate(input, Strictness::Lenient)))),
+    }];
+
+    for solver in day
+        .parts
+        .iter()
+        .filter(|p| part.is_none_or(|part| part == p.part))
+    {
+        let (answer, stats) = time_repeated(repeat, || solver.solve(generated.as_ref()));
+        timings.push(Timing {
+            day: day.day,
+            step: Step::Part(solver.part),
+            answer: Some(answer.to_string()),
+            stats,
+            memory: memory_of(&|| drop(black_box(solver.solve(generated.as_ref())))),
+        });
+    }
+
+    Ok(timings)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+    Table,
+    Json,
+    Markdown,
+}
+
+const HEADERS: [&str; 6] = ["Day", "Step", "Answer", "Runs", "Min", "Median"];
+const MEMORY_HEADERS: [&str; 2] = ["Peak memory", "Allocations"];
+
+fn cells(timing: &Timing, with_memory: bool) -> Vec<String> {
+    let mut cells = vec![
+        timing.day.to_string(),
+        timing.step.to_string(),
+        timing.answer.clone().unwrap_or_default(),
+        timing.stats.runs.to_string(),
+        format!("{:.2?}", timing.stats.min),
+        format!("{:.2?}", timing.stats.median),
+    ];
+    if with_memory {
+        cells.extend(match timing.memory {
+            Some(memory) => [
+                Bytes(memory.peak_bytes).to_string(),
+                memory.allocations.to_string(),
+            ],
+            None => Default::default(),
+        });
+    }
+    cells
+}
+
+pub fn render(timings: &[Timing], format: ReportFormat) -> String {
+    let with_memory = timings.iter().any(|t| t.memory.is_some());
+    let headers = HEADERS
+        .iter()
+        .chain(with_memory.then_some(&MEMORY_HEADERS).into_iter().flatten())
+        .copied()
+        .collect_vec();
+    let rows = timings.iter().map(|t| cells(t, with_memory)).collect_vec();
+
+    match format {
+        ReportFormat::Json => serde_json::to_string_pretty(timings).unwrap() + "\n",
+        ReportFormat::Markdown => {
+            let mut out = String::new();
+            writeln!(out, "| {} |", headers.join(" | ")).unwrap();
+            writeln!(out, "|{}", "---|".repeat(headers.len())).unwrap();
+            for row in rows {
+                writeln!(out, "| {} |", row.join(" | ")).unwrap();
+            }
+            out
+        }
+        ReportFormat::Table => render_table(&headers, &rows),
+    }
+}
+
+/// Lays out the rows in left-aligned columns under the headers.
+pub(crate) fn render_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let widths = (0..headers.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain([headers[i].chars().count()])
+                .max()
+                .unwrap()
+        })
+        .collect_vec();
+
+    let line = |row: &[&str]| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .join("  ")
+            .trim_end()
+            .to_owned()
+    };
+
+    let mut out = String::new();
+    writeln!(out, "{}", line(headers)).unwrap();
+    for row in rows {
+        writeln!(
+            out,
+            "{}",
+            line(&row.iter().map(String::as_str).collect_vec())
+        )
+        .unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{render, ReportFormat, Stats, Step, Timing};
+    use crate::memory::MemoryStats;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(
+            Stats::from_samples(vec![ms(5), ms(1), ms(3)]),
+            Stats {
+                runs: 3,
+                min: ms(1),
+                median: ms(3)
+            }
+        );
+        assert_eq!(
+            Stats::from_samples(vec![ms(4), ms(1), ms(2), ms(8)]).median,
+            ms(3)
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let stats = Stats {
+            runs: 2,
+            min: Duration::from_micros(1500),
+            median: Duration::from_millis(2),
+        };
+        let mut timings = [
+            Timing {
+                day: 4,
+                step: Step::Parse,
+                answer: None,
+                stats,
+                memory: None,
+            },
+            Timing {
+                day: 4,
+                step: Step::Part(1),
+                answer: Some("18".to_owned()),
+                stats,
+                memory: None,
+            },
+        ];
+
+        assert_eq!(
+            render(&timings, ReportFormat::Markdown),
+            "| Day | Step | Answer | Runs | Min | Median |\n\
+             |---|---|---|---|---|---|\n\
+             | 4 | parse |  | 2 | 1.50ms | 2.00ms |\n\
+             | 4 | part1 | 18 | 2 | 1.50ms | 2.00ms |\n"
+        );
+        assert_eq!(
+            render(&timings, ReportFormat::Table),
+            "Day  Step   Answer  Runs  Min     Median\n\
+             4    parse          2     1.50ms  2.00ms\n\
+             4    part1  18      2     1.50ms  2.00ms\n"
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&timings, ReportFormat::Json)).unwrap();
+        assert_eq!(
+            json[1],
+            serde_json::json!({
+                "day": 4,
+                "step": "part1",
+                "answer": "18",
+                "runs": 2,
+                "min_ns": 1_500_000,
+                "median_ns": 2_000_000,
+            })
+        );
+        assert_eq!(json[0]["step"], "parse");
+
+        timings[1].memory = Some(MemoryStats {
+            peak_bytes: 2048,
+            allocations: 3,
+        });
+        assert_eq!(
+            render(&timings, ReportFormat::Table),
+            "Day  Step   Answer  Runs  Min     Median  Peak memory  Allocations\n\
+             4    parse          2     1.50ms  2.00ms\n\
+             4    part1  18      2     1.50ms  2.00ms  2.00 KiB     3\n"
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&timings, ReportFormat::Json)).unwrap();
+        assert_eq!(json[1]["peak_bytes"], 2048);
+        assert_eq!(json[1]["allocations"], 3);
+        assert!(json[0].get("peak_bytes").is_none());
+    }
+}
@@ -0,0 +1,165 @@
+use std::cell::RefCell;
+
+use clap::ValueEnum;
+use itertools::Itertools;
+use serde::{ser::SerializeMap, Serialize, Serializer};
+use serde_json::Value;
+
+/// Something a solver observed while computing its answer, such as a verdict or a match.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub kind: &'static str,
+    pub fields: Vec<(&'static str, Value)>,
+}
+
+impl Serialize for Event {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.fields.len() + 1))?;
+        map.serialize_entry("kind", self.kind)?;
+        for (name, value) in &self.fields {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+thread_local! {
+    static EVENTS: RefCell<Option<Vec<Event>>> = const { RefCell::new(None) };
+}
+
+/// Whether the current thread is inside [`record`].
+pub fn is_enabled() -> bool {
+    EVENTS.with_borrow(Option::is_some)
+}
+
+/// Records an event if the current thread is inside [`record`]. The fields are only built when
+/// tracing, so solvers can trace freely on their fast paths. Use [`trace!`] instead of calling
+/// this directly.
+pub fn emit(kind: &'static str, fields: impl FnOnce() -> Vec<(&'static str, Value)>) {
+    EVENTS.with_borrow_mut(|events| {
+        if let Some(events) = events {
+            events.push(Event {
+                kind,
+                fields: fields(),
+            });
+        }
+    });
+}
+
+/// Runs `f` while collecting the events traced on the current thread.
+pub fn record<T>(f: impl FnOnce() -> T) -> (T, Vec<Event>) {
+    let outer = EVENTS.replace(Some(vec![]));
+    let value = f();
+    let events = EVENTS.replace(outer).unwrap_or_default();
+    (value, events)
+}
+
+/// Traces an event of the given kind with named fields, any value serializable to JSON.
+///
+/// ```ignore
+/// trace!("report", index = i, safe = true);
+/// ```
+macro_rules! trace {
+    ($kind:literal $(, $field:ident = $value:expr)* $(,)?) => {
+        $crate::trace::emit($kind, || {
+            vec![$((stringify!($field), ::serde_json::json!($value))),*]
+        })
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TraceFormat {
+    Text,
+    Json,
+}
+
+/// The answer of a part together with the events traced while solving it.
+#[derive(Debug, Serialize)]
+pub struct PartTrace {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub events: Vec<Event>,
+}
+
+impl PartTrace {
+    /// Renders the trace as the answer line followed by an indented line per event, or as a
+    /// single JSON line.
+    pub fn render(&self, format: TraceFormat) -> String {
+        match format {
+            TraceFormat::Json => serde_json::to_string(self).unwrap() + "\n",
+            TraceFormat::Text => {
+                let separator = if self.answer.contains('\n') {
+                    "\n"
+                } else {
+                    " "
+                };
+                let mut out = format!(
+                    "Day {} - Part {}:{separator}{}\n",
+                    self.day, self.part, self.answer
+                );
+                for event in &self.events {
+                    let fields = event
+                        .fields
+                        .iter()
+                        .map(|(name, value)| format!(" {name}={value}"))
+                        .join("");
+                    out += &format!("  {}{fields}\n", event.kind);
+                }
+                out
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{is_enabled, record, PartTrace, TraceFormat};
+
+    #[test]
+    fn test_record() {
+        trace!("ignored", value = 1);
+        assert!(!is_enabled());
+
+        let (answer, events) = record(|| {
+            assert!(is_enabled());
+            trace!("report", index = 0, levels = [1, 2, 3], safe = true);
+            let (_, inner) = record(|| trace!("inner"));
+            assert_eq!(inner.len(), 1);
+            trace!("report", index = 1, safe = false);
+            42
+        });
+        assert!(!is_enabled());
+        assert_eq!(answer, 42);
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events[1].fields,
+            vec![("index", json!(1)), ("safe", json!(false))]
+        );
+
+        let trace = PartTrace {
+            day: 2,
+            part: 1,
+            answer: "42".to_owned(),
+            events,
+        };
+        assert_eq!(
+            trace.render(TraceFormat::Text),
+            "Day 2 - Part 1: 42\n  report index=0 levels=[1,2,3] safe=true\n  report index=1 safe=false\n"
+        );
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&trace.render(TraceFormat::Json)).unwrap(),
+            json!({
+                "day": 2,
+                "part": 1,
+                "answer": "42",
+                "events": [
+                    {"kind": "report", "index": 0, "levels": [1, 2, 3], "safe": true},
+                    {"kind": "report", "index": 1, "safe": false},
+                ],
+            })
+        );
+    }
+}
@@ -1,44 +1,35 @@
 use std::{
-    env,
     fmt::Display,
-    io::Write,
     process::{Command, Stdio},
 };
 
 use petgraph::{dot::Dot, EdgeType, Graph};
 
-pub trait OpenGraph {
-    fn open_graph(&self);
+use crate::debug_output;
+
+pub trait ExportGraph {
+    /// Writes the graph as a `.dot` file in the debug output of the part, along with an `.svg`
+    /// rendering when `neato` is installed. Does nothing when debug output is off.
+    fn export_graph(&self);
 }
 
-impl<N, E, D: EdgeType> OpenGraph for Graph<N, E, D>
+impl<N, E, D: EdgeType> ExportGraph for Graph<N, E, D>
 where
     N: Display,
     E: Display,
 {
-    fn open_graph(&self) {
-        if env::var("OPEN_GRAPH").is_ok() {
-            let graph_dot = Dot::new(self).to_string();
-
-            let mut dot = Command::new("neato")
-                .arg("-Tpdf")
-                .stdin(Stdio::piped())
-                .stdout(Stdio::piped())
-                .stderr(Stdio::null())
-                .spawn()
-                .unwrap();
-
-            dot.stdin
-                .as_mut()
-                .unwrap()
-                .write_all(graph_dot.as_bytes())
-                .unwrap();
+    fn export_graph(&self) {
+        let Some(path) = debug_output::write_text("graph.dot", &Dot::new(self).to_string()) else {
+            return;
+        };
 
-            Command::new("zathura")
-                .arg("-")
-                .stdin(Stdio::from(dot.stdout.unwrap()))
-                .spawn()
-                .unwrap();
-        }
+        // The dot file is the artifact, the rendering is only a convenience.
+        let _ = Command::new("neato")
+            .arg("-Tsvg")
+            .arg("-o")
+            .arg(path.with_extension("svg"))
+            .arg(&path)
+            .stderr(Stdio::null())
+            .status();
     }
 }
@@ -5,19 +5,22 @@ use itertools::{Itertools, MinMaxResult};
 use ndarray::{Array2, ArrayBase, Axis, Data, Ix2};
 
 use super::ToIndex;
+use crate::debug_output;
 
-pub fn print_map(map: &ArrayBase<impl Data<Elem = bool>, Ix2>) {
-    let repr = map
-        .rows()
+pub fn render_map(map: &ArrayBase<impl Data<Elem = bool>, Ix2>) -> String {
+    map.rows()
         .into_iter()
         .map(|row| {
             row.into_iter()
                 .map(|v| if *v { "##" } else { "  " })
                 .join("")
         })
-        .join("\n");
+        .join("\n")
+}
 
-    println!("{repr}");
+/// Writes the map to the debug output of the part, doing nothing when debug output is off.
+pub fn print_map(map: &ArrayBase<impl Data<Elem = bool>, Ix2>) {
+    debug_output::write_text("map.txt", &(render_map(map) + "\n"));
 }
 
 pub trait GenerateBooleanMap {
@@ -12,11 +12,11 @@ pub mod cycle_finder;
 pub mod save_image;
 pub mod grid_utils;
 pub mod parsers;
+pub mod normalize;
+pub mod ocr;
 pub mod graph_export;
 
-pub use flood_fill::*;
 pub use position::*;
-pub use ranges::*;
 
 pub trait FromRows<A> {
     fn from_rows<R: Iterator<Item = I>, I: Iterator<Item = A>>(rows: R) -> Self;
@@ -0,0 +1,90 @@
+use std::borrow::Cow;
+
+use super::parsers::ParseError;
+
+const BOM: char = '\u{FEFF}';
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Strictness {
+    /// Trailing whitespace and blank lines at the end of the input are dropped.
+    #[default]
+    Lenient,
+    /// Only a single final newline is accepted after the last line.
+    Strict,
+}
+
+pub fn normalize_input(input: &str, strictness: Strictness) -> Result<Cow<'_, str>, ParseError> {
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+
+    let input = if input.contains('\r') {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    };
+
+    let content_len = match strictness {
+        Strictness::Lenient => input.trim_end().len(),
+        Strictness::Strict => {
+            let content = input.strip_suffix('\n').unwrap_or(&input);
+            let trimmed = content.trim_end();
+            if trimmed.len() != content.len() {
+                return Err(ParseError::at(
+                    &input,
+                    trimmed.chars().count(),
+                    vec![None],
+                    Some("trailing whitespace is not allowed in strict mode".to_owned()),
+                ));
+            }
+            content.len()
+        }
+    };
+
+    Ok(match input {
+        Cow::Borrowed(input) => Cow::Borrowed(&input[..content_len]),
+        Cow::Owned(mut input) => {
+            input.truncate(content_len);
+            Cow::Owned(input)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize_input, Strictness};
+
+    #[test]
+    fn test_normalize_lenient() {
+        for input in [
+            "1 2\n3 4",
+            "1 2\n3 4\n",
+            "1 2\n3 4\n\n\n",
+            "1 2\r\n3 4\r\n",
+            "\u{FEFF}1 2\r\n3 4 \n",
+        ] {
+            assert_eq!(
+                normalize_input(input, Strictness::Lenient).unwrap(),
+                "1 2\n3 4",
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize_strict() {
+        for input in ["1 2\n3 4", "1 2\n3 4\n", "\u{FEFF}1 2\r\n3 4\r\n"] {
+            assert_eq!(
+                normalize_input(input, Strictness::Strict).unwrap(),
+                "1 2\n3 4",
+                "{input:?}"
+            );
+        }
+
+        let error = normalize_input("1 2\n3 4\n\n", Strictness::Strict).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.found, Some('\n'));
+
+        let error = normalize_input("1 2\n3 4  ", Strictness::Strict).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.found, Some(' '));
+    }
+}
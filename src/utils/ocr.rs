@@ -0,0 +1,226 @@
+use std::fmt::{self, Display};
+
+use itertools::Itertools;
+use ndarray::{s, Array2, ArrayView2, Axis};
+
+/// Letters of the 4x6 font, trimmed to their drawn columns.
+const SMALL_FONT: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Letters of the 6x10 font, trimmed to their drawn columns.
+#[rustfmt::skip]
+const LARGE_FONT: &[(char, &str)] = &[
+    ('A', "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#"),
+    ('B', "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####."),
+    ('C', ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####."),
+    ('E', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('F', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('G', ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#"),
+    ('H', "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#"),
+    ('J', "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###.."),
+    ('K', "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#"),
+    ('L', "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('N', "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#"),
+    ('P', "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('R', "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#"),
+    ('X', "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#"),
+    ('Z', "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######"),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnrecognizedGlyph {
+    /// Column of the art where the glyph starts.
+    pub column: usize,
+    /// The glyph drawn with `#` and `.`.
+    pub glyph: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    UnsupportedHeight(usize),
+    Unrecognized {
+        /// The recognized text, with `?` in place of each unrecognized glyph.
+        text: String,
+        glyphs: Vec<UnrecognizedGlyph>,
+    },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::UnsupportedHeight(height) => write!(
+                f,
+                "letters are {height} rows high, only 6 and 10 rows high letters are recognized"
+            ),
+            OcrError::Unrecognized { text, glyphs } => {
+                write!(f, "could not recognize every letter of {text:?}")?;
+                for glyph in glyphs {
+                    write!(f, "\nat column {}:\n{}", glyph.column, glyph.glyph)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+fn draw(glyph: ArrayView2<bool>) -> String {
+    glyph
+        .rows()
+        .into_iter()
+        .map(|row| row.iter().map(|v| if *v { '#' } else { '.' }).join(""))
+        .join("\n")
+}
+
+/// Reads the capital letters drawn in `art` with the 4x6 or 6x10 block-letter fonts. Letters
+/// are told apart by the empty columns between them, and empty rows around them are ignored.
+pub fn recognize(art: &Array2<bool>) -> Result<String, OcrError> {
+    let drawn_rows = art
+        .axis_iter(Axis(0))
+        .positions(|row| row.iter().any(|v| *v))
+        .collect_vec();
+    let (Some(&top), Some(&bottom)) = (drawn_rows.first(), drawn_rows.last()) else {
+        return Ok(String::new());
+    };
+    let art = art.slice(s![top..=bottom, ..]);
+
+    let font = match art.nrows() {
+        6 => SMALL_FONT,
+        10 => LARGE_FONT,
+        height => return Err(OcrError::UnsupportedHeight(height)),
+    };
+
+    let drawn_columns = art
+        .axis_iter(Axis(1))
+        .map(|column| column.iter().any(|v| *v))
+        .collect_vec();
+    let glyphs = drawn_columns
+        .iter()
+        .enumerate()
+        .chunk_by(|(_, drawn)| **drawn)
+        .into_iter()
+        .filter(|(drawn, _)| *drawn)
+        .map(|(_, columns)| {
+            let columns = columns.map(|(i, _)| i).collect_vec();
+            (columns[0], columns[columns.len() - 1])
+        })
+        .collect_vec();
+
+    let mut text = String::new();
+    let mut unrecognized = vec![];
+    for (start, end) in glyphs {
+        let glyph = draw(art.slice(s![.., start..=end]));
+        match font.iter().find(|(_, letter)| *letter == glyph) {
+            Some((letter, _)) => text.push(*letter),
+            None => {
+                text.push('?');
+                unrecognized.push(UnrecognizedGlyph {
+                    column: start,
+                    glyph,
+                });
+            }
+        }
+    }
+
+    if unrecognized.is_empty() {
+        Ok(text)
+    } else {
+        Err(OcrError::Unrecognized {
+            text,
+            glyphs: unrecognized,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::Array2;
+
+    use super::{recognize, OcrError, UnrecognizedGlyph};
+    use crate::utils::array_from_vector;
+
+    fn art(drawing: &str) -> Array2<bool> {
+        array_from_vector(
+            drawing
+                .lines()
+                .map(|line| line.chars().map(|c| c == '#').collect())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_recognize_small() {
+        let drawing = "\
+...................................
+#..#.###..####.#....###...##..#...#
+#..#.#..#.#....#....#..#.#..#.#...#
+####.###..###..#....#..#.#..#..#.#.
+#..#.#..#.#....#....###..#..#...#..
+#..#.#..#.#....#....#.#..#..#...#..
+#..#.###..####.####.#..#..##....#..";
+
+        assert_eq!(recognize(&art(drawing)).unwrap(), "HBELROY");
+    }
+
+    #[test]
+    fn test_recognize_large() {
+        let drawing = "\
+#....#..#####.
+#....#..#....#
+.#..#...#....#
+.#..#...#....#
+..##....#####.
+..##....#.....
+.#..#...#.....
+.#..#...#.....
+#....#..#.....
+#....#..#.....";
+
+        assert_eq!(recognize(&art(drawing)).unwrap(), "XP");
+    }
+
+    #[test]
+    fn test_recognize_unknown() {
+        let drawing = "\
+.##...#.
+#..#.##.
+#..#..#.
+####..#.
+#..#..#.
+#..#.###";
+
+        assert_eq!(
+            recognize(&art(drawing)),
+            Err(OcrError::Unrecognized {
+                text: "A?".to_owned(),
+                glyphs: vec![UnrecognizedGlyph {
+                    column: 5,
+                    glyph: ".#.\n##.\n.#.\n.#.\n.#.\n###".to_owned(),
+                }],
+            })
+        );
+        assert_eq!(
+            recognize(&art("#.#\n.#.")),
+            Err(OcrError::UnsupportedHeight(2))
+        );
+    }
+}
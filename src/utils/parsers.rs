@@ -1,8 +1,13 @@
-use std::{fmt::Debug, str::FromStr};
+use std::{
+    fmt::{self, Debug, Display},
+    ops::Range,
+    str::FromStr,
+};
 
-use chumsky::{prelude::*, text::Character, Error};
+use chumsky::{error::SimpleReason, prelude::*, text::Character, Error};
+use itertools::Itertools;
 use ndarray::Array2;
-use num_traits::Num;
+use num_traits::{Num, Unsigned};
 
 use super::array_from_vector;
 
@@ -38,10 +43,280 @@ where
         .unwrapped()
 }
 
+/// A line of `N` unsigned integers separated by spaces or tabs, with any spacing around them.
+/// Numbers that do not fit in `T` and lines with another number of columns are errors.
+pub fn columns<T: Unsigned + FromStr, const N: usize>(
+) -> impl Parser<char, [T; N], Error = Simple<char>>
+where
+    <T as FromStr>::Err: Display,
+{
+    let digits = filter(|c: &char| c.is_ascii_digit())
+        .repeated()
+        .at_least(1)
+        .collect::<String>()
+        .map_with_span(|digits, span| (digits, span));
+    let spacing = one_of(" \t").repeated().at_least(1);
+
+    digits
+        .separated_by(spacing)
+        .allow_leading()
+        .allow_trailing()
+        .at_least(1)
+        .try_map(|line: Vec<(String, Range<usize>)>, _| {
+            let found = line.len();
+            if found != N {
+                let span = match line.get(N) {
+                    Some((_, extra)) => extra.clone(),
+                    None => line[found - 1].1.end..line[found - 1].1.end,
+                };
+                return Err(Simple::custom(
+                    span,
+                    format!("expected {N} columns, found {found}"),
+                ));
+            }
+
+            let values = line
+                .into_iter()
+                .map(|(digits, span)| {
+                    digits
+                        .parse::<T>()
+                        .map_err(|e| Simple::custom(span, format!("invalid number {digits}: {e}")))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(values
+                .try_into()
+                .unwrap_or_else(|_| unreachable!("the column count was checked")))
+        })
+}
+
 pub fn digit<E: Error<char>>() -> impl Parser<char, u8, Error = E> {
-    filter(|c: &char| c.is_digit(10)).map(|c| c.to_digit(10).unwrap() as u8)
+    filter(|c: &char| c.is_ascii_digit()).map(|c| c.to_digit(10).unwrap() as u8)
 }
 
 pub fn alphanumeric<E: Error<char>>() -> impl Parser<char, char, Error = E> {
     filter(|c: &char| c.is_alphanumeric())
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub source_line: String,
+    pub found: Option<char>,
+    pub expected: Vec<Option<char>>,
+    pub message: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(input: &str, error: &Simple<char>) -> Self {
+        let message = match error.reason() {
+            SimpleReason::Unexpected => error.label().map(|label| format!("invalid {label}")),
+            SimpleReason::Unclosed { delimiter, .. } => Some(format!(
+                "unclosed delimiter {}",
+                describe(&Some(*delimiter))
+            )),
+            SimpleReason::Custom(message) => Some(message.clone()),
+        };
+
+        let expected = error
+            .expected()
+            .copied()
+            .map(|c| match c {
+                Some('\r' | '\x0B' | '\x0C' | '\u{85}' | '\u{2028}' | '\u{2029}') => Some('\n'),
+                c => c,
+            })
+            .sorted()
+            .dedup()
+            .collect();
+
+        Self::at(input, error.span().start, expected, message)
+    }
+
+    pub fn at(
+        input: &str,
+        offset: usize,
+        expected: Vec<Option<char>>,
+        message: Option<String>,
+    ) -> Self {
+        let line_start = input
+            .chars()
+            .take(offset)
+            .positions(|c| c == '\n')
+            .last()
+            .map_or(0, |newline| newline + 1);
+        let line = input
+            .chars()
+            .take(line_start)
+            .filter(|c| *c == '\n')
+            .count()
+            + 1;
+        let source_line = input
+            .chars()
+            .skip(line_start)
+            .take_while(|c| *c != '\n')
+            .collect::<String>();
+
+        Self {
+            line,
+            column: offset - line_start + 1,
+            source_line: source_line.trim_end_matches('\r').to_owned(),
+            found: input.chars().nth(offset),
+            expected,
+            message,
+        }
+    }
+}
+
+fn describe(token: &Option<char>) -> String {
+    match token {
+        None => "end of input".to_owned(),
+        Some('\n') => "newline".to_owned(),
+        Some(c) => format!("{c:?}"),
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "error: unexpected {}", describe(&self.found))?;
+        if let Some(message) = &self.message {
+            write!(f, ", {message}")?;
+        }
+        match self.expected.as_slice() {
+            [] => {}
+            [expected] => write!(f, ", expected {}", describe(expected))?,
+            [expected @ .., last] => write!(
+                f,
+                ", expected one of {} or {}",
+                expected.iter().map(describe).join(", "),
+                describe(last)
+            )?,
+        }
+        writeln!(f)?;
+
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        writeln!(f, "{margin}--> line {}, column {}", self.line, self.column)?;
+        writeln!(f, "{margin} |")?;
+        writeln!(f, "{number} | {}", self.source_line)?;
+        // Tabs are kept so the caret lines up however wide they are displayed.
+        let padding = self
+            .source_line
+            .chars()
+            .chain(std::iter::repeat(' '))
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+        write!(f, "{margin} | {padding}^")
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub trait ParseInput<T>: Parser<char, T, Error = Simple<char>> + Sized {
+    fn parse_input(&self, input: &str) -> Result<T, ParseError> {
+        self.parse(input)
+            .map_err(|errors| ParseError::new(input, &errors[0]))
+    }
+}
+
+impl<T, P: Parser<char, T, Error = Simple<char>>> ParseInput<T> for P {}
+
+#[cfg(test)]
+mod tests {
+    use chumsky::prelude::*;
+
+    use super::{columns, number, ParseInput};
+
+    fn lines<T, const N: usize>() -> impl Parser<char, Vec<[T; N]>, Error = Simple<char>>
+    where
+        T: num_traits::Unsigned + std::str::FromStr,
+        <T as std::str::FromStr>::Err: std::fmt::Display,
+    {
+        columns()
+            .separated_by(text::newline())
+            .at_least(1)
+            .then_ignore(end())
+    }
+
+    fn parser() -> impl Parser<char, Vec<Vec<u32>>, Error = Simple<char>> {
+        number()
+            .separated_by(just(' '))
+            .at_least(1)
+            .separated_by(text::newline())
+            .at_least(1)
+            .then_ignore(end())
+    }
+
+    #[test]
+    fn test_parse_error_report() {
+        let error = parser().parse_input("1 2 3\n4 5x 6\n7 8 9").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.source_line, "4 5x 6");
+        assert_eq!(error.found, Some('x'));
+        assert_eq!(
+            error.to_string(),
+            [
+                "error: unexpected 'x', expected one of end of input, newline or ' '",
+                " --> line 2, column 4",
+                "  |",
+                "2 | 4 5x 6",
+                "  |    ^",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_parse_error_end_of_input() {
+        let error = parser().parse_input("1 2\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.found, None);
+        assert_eq!(error.source_line, "");
+    }
+
+    #[test]
+    fn test_columns() {
+        assert_eq!(
+            lines::<u64, 2>()
+                .parse_input("3   4\n\t12\t 00007  \n18446744073709551615 0")
+                .unwrap(),
+            vec![[3, 4], [12, 7], [u64::MAX, 0]]
+        );
+        assert_eq!(
+            lines::<u8, 3>().parse_input("1 2 3").unwrap(),
+            vec![[1, 2, 3]]
+        );
+
+        let error = lines::<u64, 2>()
+            .parse_input("1 2\n3 4 5\n6 7")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(
+            error.message.as_deref(),
+            Some("expected 2 columns, found 3")
+        );
+
+        let error = lines::<u64, 2>().parse_input("3\t\t4\t5").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 6));
+        assert_eq!(
+            error.to_string().lines().skip(3).collect::<Vec<_>>(),
+            ["1 | 3\t\t4\t5", "  |  \t\t \t^"]
+        );
+
+        let error = lines::<u64, 2>().parse_input("1 2\n3").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(
+            error.message.as_deref(),
+            Some("expected 2 columns, found 1")
+        );
+
+        let error = lines::<u32, 2>().parse_input("1 4294967296").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(
+            error.message.as_deref(),
+            Some("invalid number 4294967296: number too large to fit in target type")
+        );
+    }
+}
@@ -35,6 +35,12 @@ impl<T: PartialOrd + Integer + Add<T> + Copy> MyRange<T> for RangeInclusive<T> {
     }
 }
 
+pub type Positions<'a> = iter::FlatMap<
+    btree_map::Iter<'a, i64, i64>,
+    <RangeInclusive<i64> as IntoIterator>::IntoIter,
+    fn((&i64, &i64)) -> RangeInclusive<i64>,
+>;
+
 #[derive(Debug, Default, Clone)]
 pub struct Ranges {
     ranges: BTreeMap<i64, i64>,
@@ -53,14 +59,29 @@ impl FromIterator<RangeInclusive<i64>> for Ranges {
 }
 
 impl Ranges {
-    pub fn add_range(&mut self, range: RangeInclusive<i64>) {
-        //println!("Adding {range:?}");
-        let intersecting_ranges = self
+    /// Removes and returns, in order, the stored ranges overlapping `range`. As the stored
+    /// ranges are disjoint, they are the ones ending at or after its start among those starting
+    /// at or before its end.
+    fn extract_overlapping(&mut self, range: &RangeInclusive<i64>) -> Vec<RangeInclusive<i64>> {
+        let starts = self
             .ranges
-            .extract_if(|start, end| (*start..=*end).overlaps(&range))
-            .map(|(start, end)| start..=end)
+            .range(..=*range.end())
+            .rev()
+            .take_while(|(_, end)| *end >= range.start())
+            .map(|(start, _)| *start)
             .collect_vec();
 
+        starts
+            .into_iter()
+            .rev()
+            .map(|start| start..=self.ranges.remove(&start).unwrap())
+            .collect()
+    }
+
+    pub fn add_range(&mut self, range: RangeInclusive<i64>) {
+        //println!("Adding {range:?}");
+        let intersecting_ranges = self.extract_overlapping(&range);
+
         let min = *intersecting_ranges
             .first()
             .map_or(range.start(), |first| first.start().min(range.start()));
@@ -73,11 +94,7 @@ impl Ranges {
     }
 
     pub fn extract_range(&mut self, range: RangeInclusive<i64>) -> Ranges {
-        let mut intersecting_ranges = self
-            .ranges
-            .extract_if(|start, end| (*start..=*end).overlaps(&range))
-            .map(|(start, end)| start..=end)
-            .collect_vec();
+        let mut intersecting_ranges = self.extract_overlapping(&range);
 
         let mut remove_first = false;
         if let Some(first) = intersecting_ranges.first_mut() {
@@ -140,7 +157,7 @@ impl Ranges {
         unbounded
             .into_iter()
             .chain(bounds)
-            .chain(unbounded.into_iter())
+            .chain(unbounded)
             .tuple_windows()
             .map(|(r1, r2)| (r1.1, r2.0))
             .map(|(start, end)| {
@@ -164,9 +181,9 @@ impl Ranges {
 
     pub fn remove_pos(&mut self, pos: i64) {
         if let Some((start, end)) = self
-            .ranges
-            .extract_if(|start, end| *end >= pos && *start <= pos)
-            .next()
+            .extract_overlapping(&(pos..=pos))
+            .pop()
+            .map(RangeInclusive::into_inner)
         {
             if start != pos {
                 self.ranges.insert(start, pos - 1);
@@ -179,9 +196,9 @@ impl Ranges {
 
     pub fn cut_range(&mut self, pos: i64) {
         if let Some((start, end)) = self
-            .ranges
-            .extract_if(|start, end| *end >= pos && *start <= pos)
-            .next()
+            .extract_overlapping(&(pos..=pos))
+            .pop()
+            .map(RangeInclusive::into_inner)
         {
             if start != pos {
                 self.ranges.insert(start, pos - 1);
@@ -192,13 +209,7 @@ impl Ranges {
         }
     }
 
-    pub fn get_positions(
-        &self,
-    ) -> iter::FlatMap<
-        btree_map::Iter<i64, i64>,
-        <RangeInclusive<i64> as IntoIterator>::IntoIter,
-        fn((&i64, &i64)) -> RangeInclusive<i64>,
-    > {
+    pub fn get_positions(&self) -> Positions<'_> {
         self.ranges.iter().flat_map(|(&start, &end)| start..=end)
     }
 
@@ -211,7 +222,7 @@ impl Ranges {
         count
     }
 
-    pub fn iter_ranges(&self) -> btree_map::Iter<i64, i64> {
+    pub fn iter_ranges(&self) -> btree_map::Iter<'_, i64, i64> {
         self.ranges.iter()
     }
 }
@@ -296,7 +307,7 @@ mod tests {
         let ranges = Ranges::from_iter([0..=5, 10..=11]);
         let ranges = ranges.substract_from(1..=4);
 
-        assert_eq!(ranges.get_positions().collect_vec(), vec![])
+        assert_eq!(ranges.get_positions().collect_vec(), Vec::<i64>::new())
     }
 
     #[test]
@@ -321,4 +332,18 @@ mod tests {
             vec![0, 1, 3, 7, 9, 10]
         );
     }
+
+    #[test]
+    fn test_remove_and_cut() {
+        let mut ranges = Ranges::from_iter([0..=3, 6..=8]);
+
+        ranges.remove_pos(2);
+        ranges.remove_pos(5);
+        ranges.cut_range(7);
+
+        assert_eq!(
+            ranges.iter_ranges().map(|(&s, &e)| (s, e)).collect_vec(),
+            vec![(0, 1), (3, 3), (6, 6), (7, 8)]
+        );
+    }
 }
@@ -1,6 +1,8 @@
 use image::GrayImage;
 use ndarray::Array2;
 
+use crate::debug_output;
+
 pub trait ArrayAsGrayImage: Sized {
     fn to_gray_pixel_array(&self) -> Array2<u8>;
 
@@ -16,8 +18,12 @@ pub trait ArrayAsGrayImage: Sized {
             .expect("container should have the right size for the image dimensions")
     }
 
-    fn save_as_gray_image(&self, path: &str) {
-        self.to_gray_image().save(path).unwrap()
+    /// Saves the image as `<name>.png` in the debug output of the part, doing nothing when
+    /// debug output is off.
+    fn save_as_gray_image(&self, name: &str) {
+        if let Some(path) = debug_output::artifact(&format!("{name}.png")) {
+            self.to_gray_image().save(path).unwrap()
+        }
     }
 }
 
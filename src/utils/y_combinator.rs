@@ -27,10 +27,8 @@ where
 /// => Yf
 fn y<T, R>(f: impl Fn(&dyn Fn(T) -> R, T) -> R) -> impl Fn(T) -> R {
     move |t| {
-        (&|x: &dyn Apply<T, R>, y| x.apply(x, y))(
-            &|x: &dyn Apply<T, R>, y| f(&|z| x.apply(x, z), y),
-            t,
-        )
+        let self_apply = |x: &dyn Apply<T, R>, y| x.apply(x, y);
+        self_apply(&|x: &dyn Apply<T, R>, y| f(&|z| x.apply(x, z), y), t)
     }
 }
 
@@ -0,0 +1,547 @@
+use std::collections::HashMap;
+
+use chumsky::prelude::*;
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    solution::{Answer, Solution, Variant},
+    utils::parsers::{columns, ParseError, ParseInput},
+};
+
+type Input = Vec<(u64, u64)>;
+
+fn parser() -> impl Parser<char, Input, Error = Simple<char>> {
+    columns()
+        .map(|[a, b]| (a, b))
+        .separated_by(text::newline())
+        .at_least(1)
+        .then_ignore(end())
+}
+
+#[aoc_generator(day1)]
+pub fn input_generator(input: &str) -> Result<Input, ParseError> {
+    parser().parse_input(input)
+}
+
+#[aoc(day1, part1)]
+pub fn solve_part1(input: &Input) -> u128 {
+    let left = input.iter().map(|(a, _b)| a).sorted();
+    let right = input.iter().map(|(_a, b)| b).sorted();
+    // Sums of 64-bit IDs can overflow 64 bits, so they are added up as u128.
+    left.zip(right)
+        .map(|(a, b)| u128::from(a.abs_diff(*b)))
+        .sum::<u128>()
+}
+
+#[aoc(day1, part2)]
+pub fn solve_part2(input: &Input) -> u128 {
+    let left = input.iter().map(|(a, _b)| a).sorted();
+    let right = input.iter().map(|(_a, b)| b).counts();
+    left.map(|n| u128::from(*n) * u128::try_from(*right.get(n).unwrap_or(&0)).unwrap())
+        .sum::<u128>()
+}
+
+/// Pairs the lists by repeatedly taking the smallest left and right numbers left.
+pub fn solve_part1_reference(input: &Input) -> u128 {
+    let mut left = input.iter().map(|(a, _b)| *a).collect_vec();
+    let mut right = input.iter().map(|(_a, b)| *b).collect_vec();
+    let mut total = 0;
+    while let (Some(l), Some(r)) = (left.iter().position_min(), right.iter().position_min()) {
+        total += u128::from(left.swap_remove(l).abs_diff(right.swap_remove(r)));
+    }
+    total
+}
+
+pub fn solve_part2_reference(input: &Input) -> u128 {
+    input
+        .iter()
+        .map(|(a, _b)| {
+            u128::from(*a) * u128::try_from(input.iter().filter(|(_a, b)| b == a).count()).unwrap()
+        })
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum List {
+    Left,
+    Right,
+}
+
+impl List {
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn other(self) -> Self {
+        match self {
+            List::Left => List::Right,
+            List::Right => List::Left,
+        }
+    }
+
+    /// Change of the balance at and after an ID added to this list.
+    fn balance(self) -> i64 {
+        match self {
+            List::Left => 1,
+            List::Right => -1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Point {
+    value: u64,
+    counts: [usize; 2],
+    /// Left IDs minus right IDs up to `value`, relative to the offset of the block.
+    balance: i64,
+    /// Distance to the next point, 0 for the last one.
+    gap: u64,
+}
+
+/// A run of consecutive points whose balances can all be shifted at once.
+#[derive(Debug, Default)]
+struct Block {
+    points: Vec<Point>,
+    offset: i64,
+    /// Total gap of the points by relative balance.
+    gaps: HashMap<i64, u128>,
+    /// Total gap of the points with a balance of at least zero.
+    nonnegative: u128,
+    length: u128,
+    /// Sum of each gap times the absolute balance over it.
+    distance: u128,
+}
+
+impl Block {
+    fn new(points: Vec<Point>, offset: i64) -> Self {
+        let mut block = Block {
+            points,
+            offset,
+            ..Block::default()
+        };
+        for index in 0..block.points.len() {
+            block.include(index);
+        }
+        block
+    }
+
+    fn last_value(&self) -> u64 {
+        self.points[self.points.len() - 1].value
+    }
+
+    fn include(&mut self, index: usize) {
+        let Point { balance, gap, .. } = self.points[index];
+        let gap = u128::from(gap);
+        let actual = balance + self.offset;
+        *self.gaps.entry(balance).or_default() += gap;
+        self.length += gap;
+        self.distance += gap * u128::from(actual.unsigned_abs());
+        if actual >= 0 {
+            self.nonnegative += gap;
+        }
+    }
+
+    fn exclude(&mut self, index: usize) {
+        let Point { balance, gap, .. } = self.points[index];
+        let gap = u128::from(gap);
+        let actual = balance + self.offset;
+        *self.gaps.entry(balance).or_default() -= gap;
+        self.length -= gap;
+        self.distance -= gap * u128::from(actual.unsigned_abs());
+        if actual >= 0 {
+            self.nonnegative -= gap;
+        }
+    }
+
+    fn update(&mut self, index: usize, f: impl FnOnce(&mut Point)) {
+        self.exclude(index);
+        f(&mut self.points[index]);
+        self.include(index);
+    }
+
+    fn gap_with_balance(&self, actual: i64) -> u128 {
+        self.gaps.get(&(actual - self.offset)).copied().unwrap_or(0)
+    }
+
+    /// Adds one to every balance of the block if `up`, else subtracts one.
+    fn shift(&mut self, up: bool) {
+        if up {
+            // |b + 1| - |b| is 1 where b >= 0 and -1 elsewhere.
+            self.distance = self.distance + self.nonnegative - (self.length - self.nonnegative);
+            self.nonnegative += self.gap_with_balance(-1);
+            self.offset += 1;
+        } else {
+            // |b - 1| - |b| is -1 where b >= 1 and 1 elsewhere.
+            let positive = self.nonnegative - self.gap_with_balance(0);
+            self.distance = self.distance + (self.length - positive) - positive;
+            self.nonnegative = positive;
+            self.offset -= 1;
+        }
+    }
+}
+
+/// Both location lists, keeping their total distance and similarity score up to date as IDs are
+/// inserted and removed.
+///
+/// The distance of the sorted pairing is the integral over `x` of the absolute balance between
+/// the left and right IDs up to `x`. Every distinct ID is a point holding the balance up to the
+/// next one, and an update adds one to or subtracts one from the balance of all the points from
+/// its ID on. The points are split in blocks of about `sqrt(n)` that shift their balances in
+/// constant time, so updates take `O(sqrt(n))`.
+#[derive(Debug)]
+pub struct ListTracker {
+    blocks: Vec<Block>,
+    block_size: usize,
+    /// Points, including the ones no ID is left at.
+    points: usize,
+    rebuild_at: usize,
+    lens: [usize; 2],
+    similarity: u128,
+}
+
+impl Default for ListTracker {
+    fn default() -> Self {
+        Self {
+            blocks: vec![],
+            block_size: 16,
+            points: 0,
+            rebuild_at: 64,
+            lens: [0, 0],
+            similarity: 0,
+        }
+    }
+}
+
+impl ListTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_input(input: &Input) -> Self {
+        let mut tracker = Self::new();
+        for (a, b) in input {
+            tracker.insert(List::Left, *a);
+            tracker.insert(List::Right, *b);
+        }
+        tracker
+    }
+
+    pub fn len(&self, list: List) -> usize {
+        self.lens[list.index()]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lens == [0, 0]
+    }
+
+    /// Total distance between the lists paired up in sorted order, `None` while they have
+    /// different lengths.
+    pub fn distance(&self) -> Option<u128> {
+        (self.lens[0] == self.lens[1]).then(|| self.blocks.iter().map(|b| b.distance).sum())
+    }
+
+    pub fn similarity(&self) -> u128 {
+        self.similarity
+    }
+
+    pub fn insert(&mut self, list: List, value: u64) {
+        let (block, index) = self.find_or_insert(value);
+        let point = &mut self.blocks[block].points[index];
+        point.counts[list.index()] += 1;
+        self.similarity +=
+            u128::from(value) * u128::try_from(point.counts[list.other().index()]).unwrap();
+        self.lens[list.index()] += 1;
+        self.shift_from(block, index, list.balance());
+    }
+
+    /// Removes one occurrence of the ID from the list, returning whether it was there.
+    pub fn remove(&mut self, list: List, value: u64) -> bool {
+        let Some((block, index)) = self.find(value) else {
+            return false;
+        };
+        let point = &mut self.blocks[block].points[index];
+        if point.counts[list.index()] == 0 {
+            return false;
+        }
+        point.counts[list.index()] -= 1;
+        self.similarity -=
+            u128::from(value) * u128::try_from(point.counts[list.other().index()]).unwrap();
+        self.lens[list.index()] -= 1;
+        self.shift_from(block, index, -list.balance());
+        true
+    }
+
+    fn shift_from(&mut self, block: usize, index: usize, delta: i64) {
+        let first = &mut self.blocks[block];
+        for i in index..first.points.len() {
+            first.update(i, |point| point.balance += delta);
+        }
+        for block in &mut self.blocks[block + 1..] {
+            block.shift(delta > 0);
+        }
+    }
+
+    fn block_of(&self, value: u64) -> usize {
+        self.blocks
+            .partition_point(|block| block.last_value() < value)
+            .min(self.blocks.len().saturating_sub(1))
+    }
+
+    fn find(&self, value: u64) -> Option<(usize, usize)> {
+        let block = self.block_of(value);
+        let index = self
+            .blocks
+            .get(block)?
+            .points
+            .binary_search_by_key(&value, |p| p.value)
+            .ok()?;
+        Some((block, index))
+    }
+
+    fn find_or_insert(&mut self, value: u64) -> (usize, usize) {
+        if let Some(found) = self.find(value) {
+            return found;
+        }
+        if self.points >= self.rebuild_at {
+            self.rebuild();
+        }
+
+        let point = Point {
+            value,
+            counts: [0, 0],
+            balance: 0,
+            gap: 0,
+        };
+        if self.blocks.is_empty() {
+            self.blocks.push(Block::new(vec![point], 0));
+            self.points = 1;
+            return (0, 0);
+        }
+
+        let mut block = self.block_of(value);
+        let mut index = self.blocks[block]
+            .points
+            .partition_point(|p| p.value < value);
+        let previous = match (index, block) {
+            (0, 0) => None,
+            (0, _) => Some((block - 1, self.blocks[block - 1].points.len() - 1)),
+            _ => Some((block, index - 1)),
+        };
+        // The new point takes over the balance of the previous one from its ID on.
+        let balance = match previous {
+            Some((b, i)) => {
+                let previous = &mut self.blocks[b];
+                previous.update(i, |p| p.gap = value - p.value);
+                previous.points[i].balance + previous.offset
+            }
+            None => 0,
+        };
+
+        let next = self.blocks[block].points.get(index).map(|p| p.value);
+        let target = &mut self.blocks[block];
+        target.points.insert(
+            index,
+            Point {
+                balance: balance - target.offset,
+                gap: next.map_or(0, |next| next - value),
+                ..point
+            },
+        );
+        target.include(index);
+        self.points += 1;
+
+        if target.points.len() > 2 * self.block_size {
+            let half = target.points.len() / 2;
+            let offset = target.offset;
+            let right = target.points.split_off(half);
+            let left = std::mem::take(&mut target.points);
+            self.blocks[block] = Block::new(left, offset);
+            self.blocks.insert(block + 1, Block::new(right, offset));
+            if index >= half {
+                block += 1;
+                index -= half;
+            }
+        }
+        (block, index)
+    }
+
+    /// Drops the points no ID is left at and splits the rest in blocks of `sqrt(n)` points.
+    fn rebuild(&mut self) {
+        let mut points: Vec<Point> = vec![];
+        for block in std::mem::take(&mut self.blocks) {
+            for point in block.points {
+                let point = Point {
+                    balance: point.balance + block.offset,
+                    ..point
+                };
+                match points.last_mut() {
+                    // The balance does not change at an unused point, so its gap joins the
+                    // previous one.
+                    Some(previous) if point.counts == [0, 0] => previous.gap += point.gap,
+                    None if point.counts == [0, 0] => {}
+                    _ => points.push(point),
+                }
+            }
+        }
+        if let Some(last) = points.last_mut() {
+            last.gap = 0;
+        }
+
+        self.points = points.len();
+        self.block_size = self.points.isqrt().max(16);
+        self.rebuild_at = (2 * self.points).max(64);
+        self.blocks = points
+            .chunks(self.block_size)
+            .map(|chunk| Block::new(chunk.to_vec(), 0))
+            .collect();
+    }
+}
+
+pub fn solve_part1_tracker(input: &Input) -> u128 {
+    ListTracker::from_input(input)
+        .distance()
+        .expect("both lists should have the same length")
+}
+
+pub fn solve_part2_tracker(input: &Input) -> u128 {
+    ListTracker::from_input(input).similarity()
+}
+
+pub fn random_input(rng: &mut StdRng, size: usize) -> String {
+    const SPACING: &[&str] = &["   ", "   ", " ", "\t", " \t  "];
+
+    // Draw from a range narrow enough for numbers to repeat across the lists, with the odd
+    // 64-bit ID that makes the sums overflow 64 bits.
+    let numbers = 10_000..10_000 + 2 * size.max(1) as u64;
+    let number = |rng: &mut StdRng| match rng.gen_ratio(1, 20) {
+        true => rng.gen_range(u64::MAX / 2..=u64::MAX),
+        false => rng.gen_range(numbers.clone()),
+    };
+    (0..size.max(1))
+        .map(|_| {
+            let (a, b) = (number(rng), number(rng));
+            format!("{a}{}{b}", SPACING[rng.gen_range(0..SPACING.len())])
+        })
+        .join("\n")
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Input;
+
+    const VARIANTS: &'static [Variant<Input>] = &[
+        Variant {
+            name: "reference",
+            part: 1,
+            solve: |input| solve_part1_reference(input).into(),
+        },
+        Variant {
+            name: "reference",
+            part: 2,
+            solve: |input| solve_part2_reference(input).into(),
+        },
+        Variant {
+            name: "tracker",
+            part: 1,
+            solve: |input| solve_part1_tracker(input).into(),
+        },
+        Variant {
+            name: "tracker",
+            part: 2,
+            solve: |input| solve_part2_tracker(input).into(),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input).into()
+    }
+
+    fn random_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(random_input(rng, size))
+    }
+}
+
+examples! {
+    example1 => r#"3   4
+4   3
+2   5
+1   3
+3   9
+3   3"#, part1: "11", part2: "31";
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_tracker_matches_recomputing() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut tracker = ListTracker::new();
+        let mut lists: [Vec<u64>; 2] = [vec![], vec![]];
+
+        for step in 0..1500 {
+            let list = if rng.gen_bool(0.5) {
+                List::Left
+            } else {
+                List::Right
+            };
+            // Grow for a while, then mostly shrink so unused points get compacted.
+            let removing = rng.gen_bool(if step < 1000 { 0.3 } else { 0.7 });
+            let ids = &mut lists[list.index()];
+            if removing && !ids.is_empty() {
+                let id = ids.swap_remove(rng.gen_range(0..ids.len()));
+                assert!(tracker.remove(list, id));
+            } else {
+                let id = rng.gen_range(0..500) * rng.gen_range(1..1000);
+                ids.push(id);
+                tracker.insert(list, id);
+            }
+
+            let input = lists[0]
+                .iter()
+                .copied()
+                .zip(lists[1].iter().copied())
+                .collect_vec();
+            if lists[0].len() == lists[1].len() {
+                assert_eq!(tracker.distance(), Some(solve_part1(&input)), "step {step}");
+            } else {
+                assert_eq!(tracker.distance(), None);
+            }
+            let similarity = lists[0]
+                .iter()
+                .map(|a| {
+                    u128::from(*a)
+                        * u128::try_from(lists[1].iter().filter(|b| *b == a).count()).unwrap()
+                })
+                .sum::<u128>();
+            assert_eq!(tracker.similarity(), similarity, "step {step}");
+        }
+    }
+
+    #[test]
+    fn test_tracker_remove() {
+        let mut tracker = ListTracker::from_input(&input_generator(EXAMPLES[0].input).unwrap());
+        assert_eq!((tracker.distance(), tracker.similarity()), (Some(11), 31));
+
+        assert!(!tracker.remove(List::Left, 9));
+        assert!(!tracker.remove(List::Right, 7));
+        assert!(tracker.remove(List::Right, 9));
+        assert_eq!(tracker.distance(), None);
+        assert!(tracker.remove(List::Left, 1));
+        assert_eq!((tracker.distance(), tracker.similarity()), (Some(3), 31));
+        assert_eq!(tracker.len(List::Left), 5);
+    }
+}
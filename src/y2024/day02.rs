@@ -0,0 +1,567 @@
+use std::ops::RangeInclusive;
+
+use chumsky::{prelude::*, text::newline};
+use itertools::Itertools;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    solution::{Answer, Solution, Variant},
+    utils::parsers::{number, ParseError, ParseInput},
+};
+
+type Input = Vec<Vec<u64>>;
+
+fn parser() -> impl Parser<char, Input, Error = Simple<char>> {
+    number()
+        .separated_by(just(" "))
+        .at_least(1)
+        .separated_by(newline())
+        .at_least(1)
+        .then_ignore(end())
+}
+
+#[aoc_generator(day2)]
+pub fn input_generator(input: &str) -> Result<Input, ParseError> {
+    parser().parse_input(input)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Monotonicity {
+    Strict,
+    /// Flat steps between equal levels are safe too, whatever the range of steps.
+    NonStrict,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Each report may go either way, as long as it keeps going that way.
+    Free,
+    Increasing,
+    Decreasing,
+}
+
+/// The rules deciding whether a report is safe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    /// Allowed size of the change between two adjacent levels.
+    pub steps: RangeInclusive<u64>,
+    pub monotonicity: Monotonicity,
+    pub direction: Direction,
+}
+
+impl Default for SafetyPolicy {
+    /// The rules of the puzzle.
+    fn default() -> Self {
+        Self {
+            steps: 1..=3,
+            monotonicity: Monotonicity::Strict,
+            direction: Direction::Free,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    /// Signs of the deltas of a report going in each allowed direction.
+    fn directions(&self) -> &'static [i64] {
+        match self.direction {
+            Direction::Free => &[1, -1],
+            Direction::Increasing => &[1],
+            Direction::Decreasing => &[-1],
+        }
+    }
+
+    fn allows(&self, delta: i64, direction: i64) -> bool {
+        match delta * direction {
+            0 => self.monotonicity == Monotonicity::NonStrict,
+            change => change > 0 && self.steps.contains(&change.unsigned_abs()),
+        }
+    }
+
+    fn allows_all(&self, deltas: &[i64], direction: i64) -> bool {
+        deltas.iter().all(|delta| self.allows(*delta, direction))
+    }
+
+    pub fn is_safe(&self, levels: &[u64]) -> bool {
+        let deltas = compute_deltas(levels).collect_vec();
+        self.directions()
+            .iter()
+            .any(|direction| self.allows_all(&deltas, *direction))
+    }
+}
+
+fn compute_deltas(line: &[u64]) -> impl Iterator<Item = i64> + '_ {
+    let level = |level: u64| i64::try_from(level).expect("levels should fit in an i64");
+    line.iter()
+        .tuple_windows()
+        .map(move |(&a, &b)| level(b) - level(a))
+}
+
+pub fn count_safe(input: &Input, policy: &SafetyPolicy) -> usize {
+    input
+        .iter()
+        .enumerate()
+        .filter(|(index, line)| {
+            let safe = policy.is_safe(line);
+            trace!("report", index = index, levels = line, safe = safe);
+            safe
+        })
+        .count()
+}
+
+#[aoc(day2, part1)]
+pub fn solve_part1(input: &Input) -> usize {
+    count_safe(input, &SafetyPolicy::default())
+}
+
+/// The deltas left after removing each level in turn. Removing the first or last level drops a
+/// delta, removing any other merges the two deltas around it.
+fn deltas_without_one_level(deltas: &[i64]) -> impl Iterator<Item = Vec<i64>> + '_ {
+    let ends = [deltas[1..].to_vec(), deltas[..deltas.len() - 1].to_vec()];
+    let inner = (1..deltas.len()).map(|i| {
+        let mut merged = deltas[..i - 1].to_vec();
+        merged.push(deltas[i - 1] + deltas[i]);
+        merged.extend_from_slice(&deltas[i + 1..]);
+        merged
+    });
+    ends.into_iter().chain(inner)
+}
+
+/// Tries every way of removing up to `max_removed` levels.
+fn are_safe_deltas_lossy_slow(policy: &SafetyPolicy, deltas: &[i64], max_removed: usize) -> bool {
+    fn safe_in_direction(
+        policy: &SafetyPolicy,
+        deltas: &[i64],
+        direction: i64,
+        max_removed: usize,
+    ) -> bool {
+        policy.allows_all(deltas, direction)
+            || (max_removed > 0
+                && !deltas.is_empty()
+                && deltas_without_one_level(deltas)
+                    .any(|deltas| safe_in_direction(policy, &deltas, direction, max_removed - 1)))
+    }
+
+    policy
+        .directions()
+        .iter()
+        .any(|direction| safe_in_direction(policy, deltas, *direction, max_removed))
+}
+
+/// Whether removing at most `max_removed` levels makes the report safe, in `O(n * max_removed)`.
+fn are_safe_deltas_lossy(policy: &SafetyPolicy, deltas: &[i64], max_removed: usize) -> bool {
+    let levels = [0]
+        .into_iter()
+        .chain(deltas.iter().scan(0, |level, delta| {
+            *level += delta;
+            Some(*level)
+        }))
+        .collect_vec();
+    let n = levels.len();
+
+    policy.directions().iter().any(|&direction| {
+        // Fewest levels removed before each level for a safe run to end on it. A safe run only
+        // skips up to `max_removed` levels between two kept ones, so only those are looked at.
+        let mut removed = Vec::with_capacity(n);
+        for j in 0..n {
+            let fewest = (j.saturating_sub(max_removed + 1)..j)
+                .filter(|&i| policy.allows(levels[j] - levels[i], direction))
+                .map(|i| removed[i] + (j - i - 1))
+                .fold(j, usize::min);
+            removed.push(fewest);
+        }
+        (n.saturating_sub(max_removed + 1)..n).any(|j| removed[j] + (n - 1 - j) <= max_removed)
+    })
+}
+
+type LossyCheck = fn(&SafetyPolicy, &[i64], usize) -> bool;
+
+fn count_lossy_safe(
+    input: &Input,
+    policy: &SafetyPolicy,
+    max_removed: usize,
+    are_safe_deltas_lossy: LossyCheck,
+) -> usize {
+    input
+        .iter()
+        .enumerate()
+        .filter(|(index, line)| {
+            let deltas = compute_deltas(line).collect_vec();
+            let safe = are_safe_deltas_lossy(policy, &deltas, max_removed);
+            trace!("report", index = index, levels = line, safe = safe);
+            safe
+        })
+        .count()
+}
+
+/// Counts the reports that are safe once at most `max_removed` of their levels are removed.
+pub fn count_safe_with_removals(input: &Input, policy: &SafetyPolicy, max_removed: usize) -> usize {
+    count_lossy_safe(input, policy, max_removed, are_safe_deltas_lossy)
+}
+
+#[aoc(day2, part2)]
+pub fn solve_part2(input: &Input) -> usize {
+    count_safe_with_removals(input, &SafetyPolicy::default(), 1)
+}
+
+pub fn solve_part2_slow(input: &Input) -> usize {
+    count_lossy_safe(
+        input,
+        &SafetyPolicy::default(),
+        1,
+        are_safe_deltas_lossy_slow,
+    )
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Safe,
+    /// Safe once the level at this index is removed.
+    SafeWithout(usize),
+    /// Unsafe even after removing a level, because of the step from the level at `position` to
+    /// the next one.
+    Unsafe {
+        delta: i64,
+        position: usize,
+    },
+}
+
+/// Judges a report with the Problem Dampener. The offending step of an unsafe report is its first
+/// step going against the direction its first change sets.
+pub fn verdict(levels: &[u64], policy: &SafetyPolicy) -> Verdict {
+    if policy.is_safe(levels) {
+        return Verdict::Safe;
+    }
+
+    let deltas = compute_deltas(levels).collect_vec();
+    let first_offense = |direction: i64| {
+        deltas
+            .iter()
+            .position(|delta| !policy.allows(*delta, direction))
+            .expect("an unsafe report offends every direction")
+    };
+
+    // Removing a level only fixes the first offending step of a direction if it is one of the
+    // two levels of that step.
+    let removed = policy
+        .directions()
+        .iter()
+        .flat_map(|&direction| {
+            let position = first_offense(direction);
+            [position, position + 1]
+        })
+        .find(|&index| {
+            let mut levels = levels.to_vec();
+            levels.remove(index);
+            policy.is_safe(&levels)
+        });
+    if let Some(index) = removed {
+        return Verdict::SafeWithout(index);
+    }
+
+    let direction = match policy.direction {
+        Direction::Free => deltas
+            .iter()
+            .find(|delta| **delta != 0)
+            .map_or(1, |d| d.signum()),
+        Direction::Increasing => 1,
+        Direction::Decreasing => -1,
+    };
+    let position = first_offense(direction);
+    Verdict::Unsafe {
+        delta: deltas[position],
+        position,
+    }
+}
+
+/// Renders the report line as it was written, with the verdict under it pointing at the level to
+/// remove or at the level the offending step leads to.
+pub fn render_verdict(line: &str, verdict: &Verdict) -> String {
+    let line = line.trim_end();
+    let (index, note) = match verdict {
+        Verdict::Safe => return format!("{line}\nsafe"),
+        Verdict::SafeWithout(index) => (*index, "safe without this level".to_owned()),
+        Verdict::Unsafe { delta, position } => (
+            position + 1,
+            format!(
+                "unsafe: step of {delta:+} from level {position} to level {}",
+                position + 1
+            ),
+        ),
+    };
+    let start = line
+        .char_indices()
+        .filter(|&(i, c)| {
+            !c.is_whitespace() && line[..i].chars().last().is_none_or(char::is_whitespace)
+        })
+        .nth(index)
+        .map(|(i, _)| i)
+        .expect("the verdict should point at a level of the line");
+    // Tabs are kept so the caret lines up however wide they are displayed.
+    let padding = line[..start]
+        .chars()
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect::<String>();
+    format!("{line}\n{padding}^ {note}")
+}
+
+/// Checks the levels directly, without going through their deltas.
+fn is_safe_report(levels: &[u64]) -> bool {
+    let safe_steps =
+        |step: fn(u64, u64) -> bool| levels.iter().tuple_windows().all(|(&a, &b)| step(a, b));
+    safe_steps(|a, b| a < b && b - a <= 3) || safe_steps(|a, b| a > b && a - b <= 3)
+}
+
+pub fn solve_part1_reference(input: &Input) -> usize {
+    input.iter().filter(|levels| is_safe_report(levels)).count()
+}
+
+/// Tries removing each level in turn.
+pub fn solve_part2_reference(input: &Input) -> usize {
+    input
+        .iter()
+        .filter(|levels| {
+            is_safe_report(levels)
+                || (0..levels.len()).any(|i| {
+                    let mut removed = levels.to_vec();
+                    removed.remove(i);
+                    is_safe_report(&removed)
+                })
+        })
+        .count()
+}
+
+pub fn random_input(rng: &mut StdRng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let direction = if rng.gen_bool(0.5) { 1 } else { -1 };
+            let mut level = rng.gen_range(20..80i64);
+            let mut levels = vec![level];
+            for _ in 1..rng.gen_range(5..=8) {
+                // Mostly safe steps, with the odd flat, reversed or too large one.
+                let step = match rng.gen_range(0..10) {
+                    0 => rng.gen_range(-3..=0),
+                    1 => rng.gen_range(4..=6),
+                    _ => rng.gen_range(1..=3),
+                };
+                level = (level + direction * step).max(1);
+                levels.push(level);
+            }
+            levels.iter().join(" ")
+        })
+        .join("\n")
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Input;
+
+    const VARIANTS: &'static [Variant<Input>] = &[
+        Variant {
+            name: "slow",
+            part: 2,
+            solve: |input| solve_part2_slow(input).into(),
+        },
+        Variant {
+            name: "reference",
+            part: 1,
+            solve: |input| solve_part1_reference(input).into(),
+        },
+        Variant {
+            name: "reference",
+            part: 2,
+            solve: |input| solve_part2_reference(input).into(),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input).into()
+    }
+
+    fn random_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(random_input(rng, size))
+    }
+}
+
+examples! {
+    example1 => r#"7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9"#, part1: "2", part2: "4";
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_part2_b() {
+        let vec = compute_deltas(&[22, 18, 20, 18, 17, 16]).collect_vec();
+        let policy = SafetyPolicy::default();
+        assert_eq!(
+            are_safe_deltas_lossy_slow(&policy, &vec, 1),
+            are_safe_deltas_lossy(&policy, &vec, 1)
+        );
+    }
+
+    fn random_policy(rng: &mut StdRng) -> SafetyPolicy {
+        let min = rng.gen_range(0..=2);
+        SafetyPolicy {
+            steps: min..=rng.gen_range(min..=4),
+            monotonicity: match rng.gen_bool(0.5) {
+                true => Monotonicity::Strict,
+                false => Monotonicity::NonStrict,
+            },
+            direction: [
+                Direction::Free,
+                Direction::Increasing,
+                Direction::Decreasing,
+            ][rng.gen_range(0..3)],
+        }
+    }
+
+    #[test]
+    fn test_lossy_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for _ in 0..3000 {
+            let policy = match rng.gen_bool(0.5) {
+                true => SafetyPolicy::default(),
+                false => random_policy(&mut rng),
+            };
+            let len = rng.gen_range(1..=9);
+            let mut level = 50i64;
+            let levels = (0..len)
+                .map(|_| {
+                    level += rng.gen_range(-5..=5);
+                    level as u64
+                })
+                .collect_vec();
+            let deltas = compute_deltas(&levels).collect_vec();
+            for max_removed in 0..=3 {
+                assert_eq!(
+                    are_safe_deltas_lossy(&policy, &deltas, max_removed),
+                    are_safe_deltas_lossy_slow(&policy, &deltas, max_removed),
+                    "{levels:?} removing {max_removed} with {policy:?}"
+                );
+            }
+            assert_eq!(
+                policy.is_safe(&levels),
+                are_safe_deltas_lossy(&policy, &deltas, 0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_count_safe_with_removals() {
+        let input = input_generator(EXAMPLES[0].input).unwrap();
+        let counts = (0..=2)
+            .map(|k| count_safe_with_removals(&input, &SafetyPolicy::default(), k))
+            .collect_vec();
+        assert_eq!(counts, vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn test_verdict() {
+        let input = input_generator(EXAMPLES[0].input).unwrap();
+        let policy = SafetyPolicy::default();
+        let verdicts = input.iter().map(|l| verdict(l, &policy)).collect_vec();
+        assert_eq!(
+            verdicts,
+            vec![
+                Verdict::Safe,
+                Verdict::Unsafe {
+                    delta: 5,
+                    position: 1
+                },
+                Verdict::Unsafe {
+                    delta: -4,
+                    position: 2
+                },
+                Verdict::SafeWithout(1),
+                Verdict::SafeWithout(2),
+                Verdict::Safe,
+            ]
+        );
+        assert_eq!(
+            verdict(&[1, 2, 7, 8, 14], &policy),
+            Verdict::Unsafe {
+                delta: 5,
+                position: 1
+            }
+        );
+
+        let lines = EXAMPLES[0].input.lines().collect_vec();
+        assert_eq!(
+            render_verdict(lines[1], &verdicts[1]),
+            "1 2 7 8 9\n    ^ unsafe: step of +5 from level 1 to level 2"
+        );
+        assert_eq!(
+            render_verdict("10  13\t12 14 ", &Verdict::SafeWithout(2)),
+            "10  13\t12 14\n      \t^ safe without this level"
+        );
+        assert_eq!(render_verdict(lines[0], &verdicts[0]), "7 6 4 2 1\nsafe");
+
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..500 {
+            let policy = random_policy(&mut rng);
+            let levels = (0..rng.gen_range(1..=8))
+                .map(|_| rng.gen_range(1..=12))
+                .collect_vec();
+            let deltas = compute_deltas(&levels).collect_vec();
+            match verdict(&levels, &policy) {
+                Verdict::Safe => assert!(policy.is_safe(&levels)),
+                Verdict::SafeWithout(index) => {
+                    assert!(!policy.is_safe(&levels));
+                    let mut removed = levels.clone();
+                    removed.remove(index);
+                    assert!(policy.is_safe(&removed));
+                }
+                Verdict::Unsafe { delta, position } => {
+                    assert!(!are_safe_deltas_lossy(&policy, &deltas, 1), "{levels:?}");
+                    assert_eq!(deltas[position], delta);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_safety_policy() {
+        let default = SafetyPolicy::default();
+        let non_strict = SafetyPolicy {
+            monotonicity: Monotonicity::NonStrict,
+            ..default.clone()
+        };
+        let increasing = SafetyPolicy {
+            direction: Direction::Increasing,
+            ..default.clone()
+        };
+        let wide = SafetyPolicy {
+            steps: 1..=5,
+            ..default.clone()
+        };
+
+        assert!(!default.is_safe(&[8, 6, 4, 4, 1]));
+        assert!(non_strict.is_safe(&[8, 6, 4, 4, 1]));
+        assert!(default.is_safe(&[7, 6, 4, 2, 1]));
+        assert!(!increasing.is_safe(&[7, 6, 4, 2, 1]));
+        assert!(increasing.is_safe(&[1, 3, 6, 7, 9]));
+        assert!(!default.is_safe(&[1, 2, 7, 8, 9]));
+        assert!(wide.is_safe(&[1, 2, 7, 8, 9]));
+
+        let input = input_generator(EXAMPLES[0].input).unwrap();
+        assert_eq!(count_safe(&input, &increasing), 1);
+        assert_eq!(count_safe_with_removals(&input, &increasing, 1), 2);
+    }
+}
@@ -0,0 +1,205 @@
+use chumsky::prelude::*;
+use rand::{rngs::StdRng, Rng};
+
+use crate::{
+    solution::{Answer, Solution, Variant},
+    utils::parsers::{digit, ParseError, ParseInput},
+};
+
+pub enum Instruction {
+    Mul(u16, u16),
+    Do,
+    Dont,
+}
+
+/// The corrupted memory, kept next to its instructions so the references can scan it without the
+/// parser.
+pub struct Input {
+    memory: String,
+    instructions: Vec<Instruction>,
+}
+
+fn parser() -> impl Parser<char, Vec<Instruction>, Error = Simple<char>> {
+    let num = || {
+        digit().repeated().at_least(1).at_most(3).map(|digits| {
+            digits
+                .into_iter()
+                .fold(0u16, |acum, v| acum * 10 + v as u16)
+        })
+    };
+
+    let mul = just("mul(")
+        .ignore_then(num())
+        .then_ignore(just(','))
+        .then(num())
+        .then_ignore(just(')'));
+
+    let do_inst = just("do()").ignored();
+    let dont_inst = just("don't()").ignored();
+
+    mul.map(|(l, r)| Instruction::Mul(l, r))
+        .or(do_inst.map(|_| Instruction::Do))
+        .or(dont_inst.map(|_| Instruction::Dont))
+        .map(Some)
+        .or(chumsky::primitive::any().map(|_| None))
+        .repeated()
+        .at_least(1)
+        .flatten()
+        .then_ignore(end())
+}
+
+#[aoc_generator(day3)]
+pub fn input_generator(input: &str) -> Result<Input, ParseError> {
+    Ok(Input {
+        memory: input.to_owned(),
+        instructions: parser().parse_input(input)?,
+    })
+}
+
+#[aoc(day3, part1)]
+pub fn solve_part1(input: &Input) -> u64 {
+    input
+        .instructions
+        .iter()
+        .filter_map(|inst| match inst {
+            Instruction::Mul(l, r) => Some((l, r)),
+            _ => None,
+        })
+        .map(|(l, r)| u64::from(*l) * u64::from(*r))
+        .sum::<u64>()
+}
+
+#[aoc(day3, part2)]
+pub fn solve_part2(input: &Input) -> u64 {
+    let mut enabled = true;
+    let mut acum = 0;
+    for (index, inst) in input.instructions.iter().enumerate() {
+        match *inst {
+            Instruction::Mul(l, r) if enabled => {
+                acum += u64::from(l) * u64::from(r);
+            }
+            Instruction::Do => {
+                if !enabled {
+                    trace!("enabled", instruction = index, enabled = true);
+                }
+                enabled = true;
+            }
+            Instruction::Dont => {
+                if enabled {
+                    trace!("enabled", instruction = index, enabled = false);
+                }
+                enabled = false;
+            }
+            _ => {}
+        }
+    }
+    acum
+}
+
+/// The multiplications written in the memory with their offsets, found by trying each offset in
+/// turn.
+fn scan_muls(memory: &str) -> impl Iterator<Item = (usize, u16, u16)> + '_ {
+    fn number(text: &str) -> Option<(u16, &str)> {
+        let len = text.bytes().take(3).take_while(u8::is_ascii_digit).count();
+        (len > 0).then(|| (text[..len].parse().unwrap(), &text[len..]))
+    }
+
+    memory.char_indices().filter_map(move |(i, _)| {
+        let (l, rest) = number(memory[i..].strip_prefix("mul(")?)?;
+        let (r, rest) = number(rest.strip_prefix(',')?)?;
+        rest.starts_with(')').then_some((i, l, r))
+    })
+}
+
+/// Scans the memory text for multiplications, without the parser.
+pub fn solve_part1_reference(input: &Input) -> u64 {
+    scan_muls(&input.memory)
+        .map(|(_, l, r)| u64::from(l) * u64::from(r))
+        .sum()
+}
+
+/// Looks back in the memory text from every multiplication for the last `do()` or `don't()`.
+pub fn solve_part2_reference(input: &Input) -> u64 {
+    scan_muls(&input.memory)
+        .filter(|(i, _, _)| {
+            let before = &input.memory[..*i];
+            before.rfind("don't()") <= before.rfind("do()")
+        })
+        .map(|(_, l, r)| u64::from(l) * u64::from(r))
+        .sum()
+}
+
+pub fn random_input(rng: &mut StdRng, size: usize) -> String {
+    const NOISE: &[&str] = &[
+        "mul[3,7]",
+        "mul(4*",
+        "mul ( 2 , 4 )",
+        "mul(1234,5)",
+        "do_not_",
+        "don't",
+        "undo(",
+        "?(",
+        ")",
+        "select()",
+        "from(",
+        "'",
+        "%&",
+        "who()",
+    ];
+
+    let mut memory = String::new();
+    for _ in 0..size.max(1) {
+        match rng.gen_range(0..10) {
+            0 => memory += "do()",
+            1 => memory += "don't()",
+            2..=5 => {
+                memory += &format!("mul({},{})", rng.gen_range(0..1000), rng.gen_range(0..1000))
+            }
+            6 if rng.gen_bool(0.1) => memory.push('\n'),
+            _ => memory += NOISE[rng.gen_range(0..NOISE.len())],
+        }
+    }
+    memory
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Input;
+
+    const VARIANTS: &'static [Variant<Input>] = &[
+        Variant {
+            name: "reference",
+            part: 1,
+            solve: |input| solve_part1_reference(input).into(),
+        },
+        Variant {
+            name: "reference",
+            part: 2,
+            solve: |input| solve_part2_reference(input).into(),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input).into()
+    }
+
+    fn random_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(random_input(rng, size))
+    }
+}
+
+examples! {
+    example1 => "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))",
+        part1: "161";
+    example2 => "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+        part1: "161", part2: "48";
+}
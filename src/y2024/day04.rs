@@ -0,0 +1,268 @@
+use cgmath::Vector2;
+use chumsky::prelude::*;
+use itertools::Itertools;
+use ndarray::Array2;
+use rand::{rngs::StdRng, Rng};
+use text::newline;
+
+use crate::{
+    solution::{Answer, Solution, Variant},
+    utils::{
+        array_from_vector,
+        grid_utils::move_elements,
+        parsers::{ParseError, ParseInput},
+    },
+};
+
+type Input = Array2<char>;
+
+fn parser() -> impl Parser<char, Input, Error = Simple<char>> {
+    one_of("XMAS")
+        .repeated()
+        .at_least(1)
+        .separated_by(newline())
+        .at_least(1)
+        .map(array_from_vector)
+        .then_ignore(end())
+}
+
+#[aoc_generator(day4)]
+pub fn input_generator(input: &str) -> Result<Input, ParseError> {
+    parser().parse_input(input)
+}
+
+#[aoc(day4, part1)]
+pub fn solve_part1(input: &Input) -> u64 {
+    let (height, width) = input.dim();
+    let x = input.map(|c| *c == 'X');
+    let m = input.map(|c| *c == 'M');
+    let a = input.map(|c| *c == 'A');
+    let s = input.map(|c| *c == 'S');
+
+    [
+        (1i8, 0),
+        (1, 1),
+        (0, 1),
+        (-1, 1),
+        (-1, 0),
+        (-1, -1),
+        (0, -1),
+        (1, -1),
+    ]
+    .into_iter()
+    .map(Vector2::from)
+    .map(|dir| {
+        [&x, &m, &a, &s]
+            .into_iter()
+            .map(|mat| mat.map(|b| *b as u8))
+            .enumerate()
+            .map(|(i, mut letter)| {
+                move_elements(&mut letter, dir * (i as i8));
+                letter
+            })
+            .fold(Array2::<u8>::zeros((height, width)), |acum, elem| {
+                acum + elem
+            })
+            .indexed_iter()
+            .filter(|(_, v)| **v == 4)
+            // The letters were moved onto the `X`, so the word reads against `dir`.
+            .inspect(|((row, col), _)| {
+                trace!("match", start = [row, col], direction = [-dir.y, -dir.x])
+            })
+            .count() as u64
+    })
+    .sum::<u64>()
+}
+
+#[aoc(day4, part2)]
+pub fn solve_part2(input: &Input) -> u64 {
+    let (height, width) = input.dim();
+    let m = input.map(|c| *c == 'M');
+    let a = input.map(|c| *c == 'A');
+    let s = input.map(|c| *c == 'S');
+
+    let find_for_directions = |dirs: [[i8; 2]; 2]| {
+        dirs.into_iter()
+            .map(Vector2::from)
+            .map(|dir| {
+                [&m, &a, &s]
+                    .into_iter()
+                    .map(|mat| mat.map(|b| *b as u8))
+                    .enumerate()
+                    .map(|(i, mut letter)| {
+                        move_elements(&mut letter, dir * (i as i8 - 1));
+                        letter
+                    })
+                    .fold(Array2::<u8>::zeros((height, width)), |acum, elem| {
+                        acum + elem
+                    })
+                    .map(|v| *v == 3)
+            })
+            .fold(Array2::<u8>::zeros((height, width)), |acum, dir| {
+                acum + dir.map(|v| *v as u8)
+            })
+    };
+
+    (find_for_directions([[1, 1], [-1, -1]]) + find_for_directions([[1, -1], [-1, 1]]))
+        .indexed_iter()
+        .filter(|(_, v)| **v == 2)
+        .inspect(|((row, col), _)| trace!("match", center = [row, col]))
+        .count() as u64
+}
+
+const DIRECTIONS: [(isize, isize); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+fn letter_at(
+    input: &Input,
+    (row, col): (usize, usize),
+    (dr, dc): (isize, isize),
+    step: isize,
+) -> Option<char> {
+    let row = row.checked_add_signed(dr * step)?;
+    let col = col.checked_add_signed(dc * step)?;
+    input.get((row, col)).copied()
+}
+
+/// Spells the word from every cell in every direction.
+pub fn solve_part1_reference(input: &Input) -> u64 {
+    input
+        .indexed_iter()
+        .map(|(pos, _)| {
+            DIRECTIONS
+                .iter()
+                .filter(|dir| {
+                    "XMAS"
+                        .chars()
+                        .zip(0..)
+                        .all(|(c, step)| letter_at(input, pos, **dir, step) == Some(c))
+                })
+                .count() as u64
+        })
+        .sum()
+}
+
+/// Reads both diagonals around every `A`.
+pub fn solve_part2_reference(input: &Input) -> u64 {
+    input
+        .indexed_iter()
+        .filter(|(pos, c)| {
+            let is_mas = |dir| {
+                let ends = [
+                    letter_at(input, *pos, dir, -1),
+                    letter_at(input, *pos, dir, 1),
+                ];
+                ends == [Some('M'), Some('S')] || ends == [Some('S'), Some('M')]
+            };
+            **c == 'A' && is_mas((1, 1)) && is_mas((1, -1))
+        })
+        .count() as u64
+}
+
+pub fn random_input(rng: &mut StdRng, size: usize) -> String {
+    let letters = ['X', 'M', 'A', 'S'];
+    (0..size.max(1))
+        .map(|_| {
+            (0..size.max(1))
+                .map(|_| letters[rng.gen_range(0..letters.len())])
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Input;
+
+    const VARIANTS: &'static [Variant<Input>] = &[
+        Variant {
+            name: "reference",
+            part: 1,
+            solve: |input| solve_part1_reference(input).into(),
+        },
+        Variant {
+            name: "reference",
+            part: 2,
+            solve: |input| solve_part2_reference(input).into(),
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        input_generator(input)
+    }
+
+    fn part1(input: &Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Input) -> Answer {
+        solve_part2(input).into()
+    }
+
+    fn random_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(random_input(rng, size))
+    }
+}
+
+examples! {
+    example1 => r#"MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX"#, part1: "18", part2: "9";
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::trace;
+
+    #[test]
+    fn test_trace_matches() {
+        let input = input_generator(EXAMPLES[0].input).unwrap();
+
+        let (count, events) = trace::record(|| solve_part1(&input));
+        assert_eq!(events.len() as u64, count);
+        assert!(events
+            .iter()
+            .any(|e| e.fields == vec![("start", json!([1, 4])), ("direction", json!([0, -1]))]));
+
+        let (count, events) = trace::record(|| solve_part2(&input));
+        assert_eq!(events.len() as u64, count);
+        assert_eq!(events[0].fields, vec![("center", json!([1, 2]))]);
+    }
+
+    #[test]
+    fn test_roll() {
+        let mat = array_from_vector(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+
+        let mut other = mat.clone();
+        move_elements(&mut other, Vector2::new(1, 0));
+        assert_eq!(
+            other,
+            array_from_vector(vec![vec![0, 1, 2], vec![0, 4, 5], vec![0, 7, 8],])
+        );
+
+        move_elements(&mut other, Vector2::new(0, -1));
+        assert_eq!(
+            other,
+            array_from_vector(vec![vec![0, 4, 5], vec![0, 7, 8], vec![0, 0, 0]])
+        );
+    }
+}
@@ -0,0 +1,11 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+
+pub static DAYS: &[crate::runner::DaySolver] = &[
+    day!(1 => day01::Day01),
+    day!(2 => day02::Day02),
+    day!(3 => day03::Day03),
+    day!(4 => day04::Day04),
+];